@@ -4,6 +4,9 @@ use bevy_ecs_ldtk::prelude::LdtkProject;
 use bevy_kira_audio::AudioSource;
 use bevy_trickfilm::prelude::*;
 
+use crate::enemy::demon_boss::phase::DemonBossPhases;
+use crate::spell::registry::SpellRegistry;
+
 #[derive(AssetCollection, Resource)]
 pub struct GameAssets {
     #[asset(path = "mage.png")]
@@ -36,8 +39,13 @@ pub struct GameAssets {
     pub demon_boss_shadow: Handle<Image>,
     #[asset(path = "enemy/enemy_boss_aura.png")]
     pub demon_boss_aura: Handle<Image>,
+    #[asset(path = "enemy/demon_boss.phases.ron")]
+    pub demon_boss_phases: Handle<DemonBossPhases>,
 
     // --- SPELL ---
+    #[asset(path = "spell/registry.spells.ron")]
+    pub spell_registry: Handle<SpellRegistry>,
+
     #[asset(path = "spell/fireball.png")]
     pub fireball_texture: Handle<Image>,
     #[asset(texture_atlas(tile_size_x = 70, tile_size_y = 11, columns = 10, rows = 6))]
@@ -200,22 +208,6 @@ pub struct GameAssets {
     #[asset(path = "ui/spell_field_selector.png")]
     pub spell_field_selector: Handle<Image>,
 
-    #[asset(path = "ui/spell_console_icon.png")]
-    pub spell_console_icon: Handle<Image>,
-    #[asset(path = "ui/fulgur_icon.png")]
-    pub fulgur_icon: Handle<Image>,
-    #[asset(path = "ui/fulgur_avis_icon.png")]
-    pub fulgur_avis_icon: Handle<Image>,
-    #[asset(path = "ui/ignis_pila_icon.png")]
-    pub ignis_pila_icon: Handle<Image>,
-    #[asset(path = "ui/inferno_pila_icon.png")]
-    pub inferno_pila_icon: Handle<Image>,
-    #[asset(path = "ui/scutum_glaciei_icon.png")]
-    pub scutum_glaciei_icon: Handle<Image>,
-    #[asset(path = "ui/aer_tracto_icon.png")]
-    pub aer_tracto_icon: Handle<Image>,
-    #[asset(path = "ui/aer_pello_icon.png")]
-    pub aer_pello_icon: Handle<Image>,
     #[asset(path = "ui/placeholder_icon.png")]
     pub placeholder_icon: Handle<Image>,
 
@@ -65,9 +65,11 @@ fn play_cast_vocals(
     mut play_sound: EventWriter<PlaySound>,
 ) {
     for demon_spell in &q_demon_spells {
+        // The new spells have no recorded lines yet, they stay silent until they do.
         let vocals = match demon_spell.spell {
             DemonSpell::Explosion => assets.demon_boss_vocal_explosion_sound.clone(),
             DemonSpell::EarthPrison => assets.demon_boss_vocal_earth_prison_sound.clone(),
+            DemonSpell::SlimeRing | DemonSpell::MeteorRain | DemonSpell::FlameLine => continue,
         };
 
         play_sound.write(PlaySound {
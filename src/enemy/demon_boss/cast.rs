@@ -1,14 +1,75 @@
-use rand::{thread_rng, Rng};
+use std::time::Duration;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::thread_rng;
+use serde::Deserialize;
 
 use bevy::prelude::*;
 
-use super::{state::DemonBossState, DemonBoss};
-use crate::GameState;
+use super::{
+    flame_line,
+    phase::{DemonBossPhases, WeightedSpell},
+    slime_ring::MAX_SUMMONS,
+    state::DemonBossState,
+    DemonBoss,
+};
+use crate::{enemy::Enemy, player::Player, GameState};
+
+/// Spells that trap the player are more likely when the player is closer than this.
+const CLOSE_DISTANCE: f32 = 200.0;
+/// Spells that cover a lot of ground are more likely when the player is further than this.
+const FAR_DISTANCE: f32 = 300.0;
 
-#[derive(Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Deserialize)]
 pub enum DemonSpell {
     Explosion,
     EarthPrison,
+    /// Summons a ring of slimes around the player.
+    SlimeRing,
+    /// Telegraphed impacts around the player.
+    MeteorRain,
+    /// A line of flames that sweeps over the player.
+    FlameLine,
+}
+
+impl DemonSpell {
+    /// Multiplier for the weight of the spell in the current phase,
+    /// `summons` are the enemies that are alive apart from the boss.
+    fn situational_weight(&self, distance: f32, summons: usize) -> f32 {
+        match self {
+            DemonSpell::Explosion => 1.0,
+            DemonSpell::EarthPrison => {
+                if distance < CLOSE_DISTANCE {
+                    1.5
+                } else {
+                    0.75
+                }
+            }
+            DemonSpell::SlimeRing => {
+                if summons >= MAX_SUMMONS {
+                    0.0
+                } else if distance > FAR_DISTANCE {
+                    1.5
+                } else {
+                    0.75
+                }
+            }
+            DemonSpell::MeteorRain => {
+                if distance > CLOSE_DISTANCE {
+                    1.5
+                } else {
+                    0.75
+                }
+            }
+            DemonSpell::FlameLine => {
+                if distance < flame_line::REACH {
+                    2.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
 }
 
 #[derive(Component, Clone)]
@@ -30,25 +91,65 @@ pub struct LastSpellTimer(Timer);
 #[derive(Event, Deref, DerefMut)]
 pub struct SpawnDemonSpell(pub DemonSpellCast);
 
-fn pick_random_spell() -> DemonSpell {
-    let mut rng = thread_rng();
+fn pick_random_spell(spells: &[WeightedSpell]) -> Option<DemonSpell> {
+    let dist = WeightedIndex::new(spells.iter().map(|s| s.weight)).ok()?;
+    Some(spells[dist.sample(&mut thread_rng())].spell)
+}
+
+/// Weighted by the phase, the situation and the recent spells.
+/// The same spell is never picked three times in a row, unless the phase has no other spell.
+fn pick_spell(
+    spells: &[WeightedSpell],
+    recent_spells: &[Option<DemonSpell>; 2],
+    distance: f32,
+    summons: usize,
+) -> DemonSpell {
+    let repeated = match recent_spells {
+        [Some(a), Some(b)] if a == b => Some(*a),
+        _ => None,
+    };
+    let weights = spells.iter().map(|s| {
+        if Some(s.spell) == repeated {
+            0.0
+        } else {
+            s.weight * s.spell.situational_weight(distance, summons)
+        }
+    });
 
-    match rng.gen_range(0..2) {
-        0 => DemonSpell::Explosion,
-        _ => DemonSpell::EarthPrison,
+    match WeightedIndex::new(weights) {
+        Ok(dist) => spells[dist.sample(&mut thread_rng())].spell,
+        // Nothing fits the situation, casting anything is still better than getting stuck.
+        // Only repeat a spell a third time if the phase has nothing else.
+        Err(_) => {
+            let others: Vec<WeightedSpell> = spells
+                .iter()
+                .filter(|s| Some(s.spell) != repeated)
+                .cloned()
+                .collect();
+            pick_random_spell(&others)
+                .or_else(|| pick_random_spell(spells))
+                .unwrap_or(DemonSpell::Explosion)
+        }
     }
 }
 
 fn spawn_demon_spell(
     mut commands: Commands,
-    q_demon_boss: Query<&DemonBoss>,
+    phases: Res<DemonBossPhases>,
+    mut q_demon_boss: Query<(&Transform, &mut DemonBoss)>,
+    q_player: Query<&Transform, (With<Player>, Without<DemonBoss>)>,
+    q_summons: Query<(), (With<Enemy>, Without<DemonBoss>)>,
     q_demon_spells: Query<&DemonSpellCast>,
     q_demon_spell_cooldowns: Query<&DemonSpellCooldown>,
 ) {
-    let demon_boss = match q_demon_boss.single() {
+    let (demon_boss_transform, mut demon_boss) = match q_demon_boss.single_mut() {
         Ok(r) => r,
         Err(_) => return,
     };
+    let player_pos = match q_player.single() {
+        Ok(p) => p.translation,
+        Err(_) => return,
+    };
 
     if demon_boss.state != DemonBossState::Casting {
         return;
@@ -60,15 +161,25 @@ fn spawn_demon_spell(
         return;
     }
 
-    let spell = pick_random_spell();
+    let phase = phases.get(&demon_boss);
+    let distance = player_pos
+        .truncate()
+        .distance(demon_boss_transform.translation.truncate());
+    let spell = pick_spell(
+        &phase.spells,
+        &demon_boss.recent_spells,
+        distance,
+        q_summons.iter().count(),
+    );
+    demon_boss.recent_spells = [demon_boss.recent_spells[1], Some(spell)];
 
     commands.spawn(DemonSpellCooldown {
         spell,
-        timer: Timer::from_seconds(5.0, TimerMode::Once),
+        timer: Timer::from_seconds(phase.spell_cooldown, TimerMode::Once),
     });
     commands.spawn(DemonSpellCast {
         spell,
-        timer: Timer::from_seconds(1.5, TimerMode::Once),
+        timer: Timer::from_seconds(phase.cast_time, TimerMode::Once),
     });
 }
 
@@ -100,12 +211,15 @@ fn despawn_spell_cooldowns(
     }
 }
 
-fn spawn_last_spell_timer(mut commands: Commands) {
-    commands.spawn(LastSpellTimer(Timer::from_seconds(10.0, TimerMode::Once)));
+fn spawn_last_spell_timer(mut commands: Commands, phases: Res<DemonBossPhases>) {
+    let time = phases.first().last_spell_time;
+    commands.spawn(LastSpellTimer(Timer::from_seconds(time, TimerMode::Once)));
 }
 
 fn reset_last_spell_timer(
     time: Res<Time>,
+    phases: Res<DemonBossPhases>,
+    q_demon_boss: Query<&DemonBoss>,
     mut q_last_spell_timer: Query<&mut LastSpellTimer>,
     mut ev_spawn_demon_spell: EventReader<SpawnDemonSpell>,
 ) {
@@ -120,6 +234,10 @@ fn reset_last_spell_timer(
     }
     ev_spawn_demon_spell.clear();
 
+    if let Ok(demon_boss) = q_demon_boss.single() {
+        let duration = phases.get(demon_boss).last_spell_time;
+        timer.set_duration(Duration::from_secs_f32(duration));
+    }
     timer.reset();
 }
 
@@ -141,3 +259,52 @@ impl Plugin for DemonBossCastPlugin {
         .add_systems(OnEnter(GameState::Gaming), spawn_last_spell_timer);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted(spells: &[DemonSpell]) -> Vec<WeightedSpell> {
+        spells
+            .iter()
+            .map(|spell| WeightedSpell {
+                spell: *spell,
+                weight: 1.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn never_picks_a_spell_three_times_in_a_row() {
+        let spells = weighted(&[DemonSpell::Explosion, DemonSpell::EarthPrison]);
+        let recent_spells = [Some(DemonSpell::Explosion), Some(DemonSpell::Explosion)];
+
+        for _ in 0..100 {
+            let spell = pick_spell(&spells, &recent_spells, 100.0, 0);
+            assert_eq!(spell, DemonSpell::EarthPrison);
+        }
+    }
+
+    #[test]
+    fn never_picks_a_spell_three_times_in_a_row_when_nothing_fits() {
+        // The flame line can't reach the player, which leaves no spell with a weight.
+        let spells = weighted(&[DemonSpell::Explosion, DemonSpell::FlameLine]);
+        let recent_spells = [Some(DemonSpell::Explosion), Some(DemonSpell::Explosion)];
+
+        for _ in 0..100 {
+            let spell = pick_spell(&spells, &recent_spells, 10_000.0, 0);
+            assert_eq!(spell, DemonSpell::FlameLine);
+        }
+    }
+
+    #[test]
+    fn repeats_the_only_spell_of_a_phase() {
+        let spells = weighted(&[DemonSpell::Explosion]);
+        let recent_spells = [Some(DemonSpell::Explosion), Some(DemonSpell::Explosion)];
+
+        assert_eq!(
+            pick_spell(&spells, &recent_spells, 100.0, 0),
+            DemonSpell::Explosion
+        );
+    }
+}
@@ -6,6 +6,8 @@ use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use bevy_trickfilm::prelude::*;
 
+use crate::spell::damage::{DamageType, Hitbox, Knockback};
+use crate::spell::Faction;
 use crate::{player::Player, world::camera::YSort, GameAssets, GameState};
 
 use super::{
@@ -17,6 +19,8 @@ use super::{
 
 const RAND_OFFSET_INTENSITY: f32 = 150.0;
 const COUNT: usize = 10;
+const STRIKE_DAMAGE: f32 = 2.0;
+const KNOCKBACK: f32 = 200.0;
 
 #[derive(Component)]
 pub struct DemonBossExplosion {
@@ -25,9 +29,7 @@ pub struct DemonBossExplosion {
 }
 
 #[derive(Component)]
-pub struct DemonBossStrikeExplosion {
-    pub damage: f32,
-}
+pub struct DemonBossStrikeExplosion;
 
 #[derive(Component, Deref, DerefMut)]
 struct ColliderTimer(Timer);
@@ -39,7 +41,7 @@ struct ExplosionDelayTimer {
     normal_explosion: bool,
 }
 
-fn spawn_normal_explosion(commands: &mut Commands, assets: &Res<GameAssets>, pos: Vec3) {
+pub fn spawn_normal_explosion(commands: &mut Commands, assets: &Res<GameAssets>, pos: Vec3) {
     let mut animator = AnimationPlayer2D::default();
     animator
         .play(assets.demon_boss_explosion_animations[0].clone())
@@ -63,7 +65,7 @@ fn spawn_normal_explosion(commands: &mut Commands, assets: &Res<GameAssets>, pos
     ));
 }
 
-fn spawn_strike_explosion(commands: &mut Commands, assets: &Res<GameAssets>, pos: Vec3) {
+pub fn spawn_strike_explosion(commands: &mut Commands, assets: &Res<GameAssets>, pos: Vec3) {
     let mut animator = AnimationPlayer2D::default();
     animator.play(assets.demon_boss_explosion2_animations[0].clone());
 
@@ -73,13 +75,19 @@ fn spawn_strike_explosion(commands: &mut Commands, assets: &Res<GameAssets>, pos
             Sensor,
             Collider::ball(20.0),
             CollisionGroups::default(),
+            Hitbox {
+                damage: STRIKE_DAMAGE,
+                damage_type: DamageType::Physical,
+                knockback: Knockback::Radial(KNOCKBACK),
+                faction: Faction::Enemy,
+            },
             Transform::default(),
         ))
         .id();
 
     commands
         .spawn((
-            DemonBossStrikeExplosion { damage: 2.0 },
+            DemonBossStrikeExplosion,
             animator,
             YSort(0.0),
             Sprite::from_atlas_image(
@@ -163,6 +171,12 @@ fn change_animations(
                     Sensor,
                     Collider::ball(15.0),
                     CollisionGroups::default(),
+                    Hitbox {
+                        damage: explosion.damage,
+                        damage_type: DamageType::Physical,
+                        knockback: Knockback::Radial(KNOCKBACK),
+                        faction: Faction::Enemy,
+                    },
                     Transform::default(),
                 ))
                 .id();
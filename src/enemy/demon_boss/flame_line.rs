@@ -0,0 +1,197 @@
+use std::f32::consts::PI;
+
+use rand::{thread_rng, Rng};
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::spell::damage::{DamageType, Hitbox, Knockback};
+use crate::spell::Faction;
+use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
+use crate::world::camera::YSort;
+use crate::{player::Player, GameAssets, GameState};
+
+use super::cast::{DemonSpell, SpawnDemonSpell};
+use super::{state::DemonBossState, DemonBoss};
+
+const SEGMENTS: usize = 5;
+const FIRST_SEGMENT_OFFSET: f32 = 40.0;
+const SEGMENT_SPACING: f32 = 45.0;
+/// How far the line reaches from the boss, see `DemonSpell::situational_weight`.
+pub const REACH: f32 = 250.0;
+/// The line is shown faded and harmless for this long before it starts sweeping.
+const WIND_UP_TIME: f32 = 0.6;
+const SWEEP_TIME: f32 = 2.0;
+const SWEEP_ANGLE: f32 = PI * 2.0 / 3.0;
+const WIND_UP_ALPHA: f32 = 0.35;
+const DAMAGE: f32 = 1.0;
+const KNOCKBACK: f32 = 150.0;
+/// The line starts at the feet of the boss rather than its center.
+const ORIGIN_OFFSET: Vec3 = Vec3::new(0.0, -30.0, 0.0);
+
+#[derive(Component)]
+struct FlameLine {
+    wind_up_timer: Timer,
+    sweep_timer: Timer,
+    start_angle: f32,
+    /// Signed, so the line can sweep either way.
+    sweep_angle: f32,
+}
+
+#[derive(Component)]
+struct FlameSegment;
+
+fn spawn_flame_lines(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    q_player: Query<&Transform, With<Player>>,
+    q_demon_boss: Query<&Transform, (With<DemonBoss>, Without<Player>)>,
+    mut ev_spawn_demon_spells: EventReader<SpawnDemonSpell>,
+) {
+    let player_pos = match q_player.single() {
+        Ok(r) => r.translation,
+        Err(_) => return,
+    };
+    let origin = match q_demon_boss.single() {
+        Ok(r) => r.translation + ORIGIN_OFFSET,
+        Err(_) => return,
+    };
+
+    for ev in ev_spawn_demon_spells.read() {
+        if ev.spell != DemonSpell::FlameLine {
+            continue;
+        }
+
+        // Start next to the player and sweep over them.
+        let angle = (player_pos - origin).truncate().to_angle();
+        let sweep_angle = if thread_rng().gen_bool(0.5) {
+            SWEEP_ANGLE
+        } else {
+            -SWEEP_ANGLE
+        };
+        let start_angle = angle - sweep_angle / 2.0;
+
+        let mut segments = Vec::new();
+        for i in 0..SEGMENTS {
+            let mut sprite = Sprite::from_atlas_image(
+                assets.fireball_texture.clone(),
+                TextureAtlas {
+                    layout: assets.fireball_layout.clone(),
+                    ..default()
+                },
+            );
+            sprite.flip_x = true;
+            sprite.color = sprite.color.with_alpha(WIND_UP_ALPHA);
+
+            let offset = FIRST_SEGMENT_OFFSET + SEGMENT_SPACING * i as f32;
+            segments.push(
+                commands
+                    .spawn((
+                        FlameSegment,
+                        AnimSprite::new(60, true),
+                        AnimSpriteTimer::new(0.05),
+                        sprite,
+                        Transform::from_translation(Vec3::X * offset),
+                    ))
+                    .id(),
+            );
+        }
+
+        commands
+            .spawn((
+                FlameLine {
+                    wind_up_timer: Timer::from_seconds(WIND_UP_TIME, TimerMode::Once),
+                    sweep_timer: Timer::from_seconds(SWEEP_TIME, TimerMode::Once),
+                    start_angle,
+                    sweep_angle,
+                },
+                YSort(10.0),
+                Visibility::default(),
+                Transform::from_translation(origin)
+                    .with_rotation(Quat::from_rotation_z(start_angle)),
+            ))
+            .add_children(&segments);
+    }
+}
+
+fn ignite_flame_lines(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_flame_lines: Query<(&mut FlameLine, &Children)>,
+    mut q_segments: Query<&mut Sprite, With<FlameSegment>>,
+) {
+    for (mut flame_line, children) in &mut q_flame_lines {
+        flame_line.wind_up_timer.tick(time.delta());
+        if !flame_line.wind_up_timer.just_finished() {
+            continue;
+        }
+
+        for child in children.iter() {
+            let mut sprite = match q_segments.get_mut(child) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+            sprite.color = sprite.color.with_alpha(1.0);
+            commands.entity(child).insert((
+                Collider::cuboid(25.0, 5.0),
+                Sensor,
+                Hitbox {
+                    damage: DAMAGE,
+                    damage_type: DamageType::Fire,
+                    knockback: Knockback::Radial(KNOCKBACK),
+                    faction: Faction::Enemy,
+                },
+            ));
+        }
+    }
+}
+
+fn sweep_flame_lines(
+    mut commands: Commands,
+    time: Res<Time>,
+    q_demon_boss: Query<(&Transform, &DemonBoss), Without<FlameLine>>,
+    mut q_flame_lines: Query<(Entity, &mut Transform, &mut FlameLine)>,
+) {
+    let demon_boss = q_demon_boss.single().ok();
+
+    for (entity, mut transform, mut flame_line) in &mut q_flame_lines {
+        // The flames die with the boss.
+        let demon_boss_pos = match demon_boss {
+            Some((t, boss)) if boss.state != DemonBossState::Dying => t.translation,
+            _ => {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        };
+
+        let z = transform.translation.z;
+        transform.translation = (demon_boss_pos + ORIGIN_OFFSET).with_z(z);
+
+        if !flame_line.wind_up_timer.finished() {
+            continue;
+        }
+
+        flame_line.sweep_timer.tick(time.delta());
+        if flame_line.sweep_timer.just_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let angle =
+            flame_line.start_angle + flame_line.sweep_angle * flame_line.sweep_timer.fraction();
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+pub struct DemonBossFlameLinePlugin;
+
+impl Plugin for DemonBossFlameLinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_flame_lines, ignite_flame_lines, sweep_flame_lines)
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use rand::{thread_rng, Rng};
+
+use bevy::prelude::*;
+
+use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
+use crate::utils::quat_from_vec2;
+use crate::world::camera::YSort;
+use crate::world::CameraShake;
+use crate::{player::Player, GameAssets, GameState};
+
+use super::cast::{DemonSpell, SpawnDemonSpell};
+use super::explosion::spawn_strike_explosion;
+
+const COUNT: usize = 7;
+const RAND_OFFSET_INTENSITY: f32 = 130.0;
+/// How long the impact circle is shown before the first meteor lands.
+const TELEGRAPH_TIME: f32 = 1.2;
+const DELAY_BETWEEN_IMPACTS: f32 = 0.25;
+/// The last part of the telegraph in which the meteor is visibly falling.
+const FALL_TIME: f32 = 0.4;
+const FALL_HEIGHT: f32 = 200.0;
+const CIRCLE_SCALE: f32 = 0.3;
+const CIRCLE_COLOR: Color = Color::srgba(1.0, 0.2, 0.1, 0.6);
+const TRAUMA: f32 = 0.25;
+const TRAUMA_THRESHOLD: f32 = 0.5;
+
+/// The telegraphed impact circle, the meteor lands once the timer finishes.
+#[derive(Component)]
+struct Meteor {
+    timer: Timer,
+    falling: Option<Entity>,
+}
+
+#[derive(Component)]
+struct FallingMeteor;
+
+fn spawn_meteors(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    q_player: Query<&Transform, With<Player>>,
+    mut ev_spawn_demon_spells: EventReader<SpawnDemonSpell>,
+) {
+    let player_pos = match q_player.single() {
+        Ok(r) => r.translation,
+        Err(_) => return,
+    };
+
+    let mut rng = thread_rng();
+
+    for ev in ev_spawn_demon_spells.read() {
+        if ev.spell != DemonSpell::MeteorRain {
+            continue;
+        }
+
+        for i in 0..COUNT {
+            // The first meteor always aims for the player directly.
+            let offset = if i == 0 {
+                Vec3::ZERO
+            } else {
+                Vec3::new(
+                    rng.gen_range(-1.0..1.0) * RAND_OFFSET_INTENSITY,
+                    rng.gen_range(-1.0..1.0) * RAND_OFFSET_INTENSITY,
+                    0.0,
+                )
+            };
+
+            let mut sprite = Sprite::from_image(assets.demon_boss_aura.clone());
+            sprite.color = CIRCLE_COLOR;
+            commands.spawn((
+                Meteor {
+                    timer: Timer::from_seconds(
+                        TELEGRAPH_TIME + DELAY_BETWEEN_IMPACTS * i as f32,
+                        TimerMode::Once,
+                    ),
+                    falling: None,
+                },
+                YSort(-1.0),
+                sprite,
+                Transform::from_translation(player_pos + offset).with_scale(Vec3::ZERO),
+            ));
+        }
+    }
+}
+
+fn update_meteors(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    time: Res<Time>,
+    mut camera_shake: ResMut<CameraShake>,
+    mut q_meteors: Query<(Entity, &Transform, &mut Meteor), Without<FallingMeteor>>,
+    mut q_falling_meteors: Query<&mut Transform, With<FallingMeteor>>,
+) {
+    for (entity, transform, mut meteor) in &mut q_meteors {
+        meteor.timer.tick(time.delta());
+        let pos = transform.translation;
+
+        if meteor.timer.just_finished() {
+            if let Some(falling) = meteor.falling {
+                commands.entity(falling).despawn();
+            }
+            commands.entity(entity).despawn();
+            spawn_strike_explosion(&mut commands, &assets, pos);
+            camera_shake.add_trauma_with_threshold(TRAUMA, TRAUMA_THRESHOLD);
+            continue;
+        }
+
+        let remaining = meteor.timer.remaining_secs();
+        if remaining > FALL_TIME {
+            continue;
+        }
+
+        let height = FALL_HEIGHT * remaining / FALL_TIME;
+        match meteor.falling {
+            Some(falling) => {
+                if let Ok(mut falling_transform) = q_falling_meteors.get_mut(falling) {
+                    falling_transform.translation = pos + Vec3::Y * height;
+                }
+            }
+            None => {
+                let mut sprite = Sprite::from_atlas_image(
+                    assets.fireball_texture.clone(),
+                    TextureAtlas {
+                        layout: assets.fireball_layout.clone(),
+                        ..default()
+                    },
+                );
+                sprite.flip_x = true;
+
+                let falling = commands
+                    .spawn((
+                        FallingMeteor,
+                        YSort(100.0),
+                        AnimSprite::new(60, true),
+                        AnimSpriteTimer::new(0.05),
+                        sprite,
+                        Transform::from_translation(pos + Vec3::Y * height)
+                            .with_rotation(quat_from_vec2(Vec2::NEG_Y)),
+                    ))
+                    .id();
+                meteor.falling = Some(falling);
+            }
+        }
+    }
+}
+
+fn scale_impact_circles(mut q_meteors: Query<(&mut Transform, &Meteor)>) {
+    for (mut transform, meteor) in &mut q_meteors {
+        // Grow quickly so the player sees where to get away from.
+        let progress = (meteor.timer.elapsed_secs() / TELEGRAPH_TIME).min(1.0);
+        transform.scale = Vec3::splat(CIRCLE_SCALE * progress.sqrt());
+    }
+}
+
+pub struct DemonBossMeteorPlugin;
+
+impl Plugin for DemonBossMeteorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_meteors, update_meteors, scale_impact_circles)
+                .chain()
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -1,12 +1,16 @@
 pub mod explosion;
+pub mod phase;
 pub mod spawn;
 
 mod audio;
 mod cast;
 mod collision;
 mod earth_prison;
+mod flame_line;
+mod meteor;
 mod movement;
 mod rage;
+mod slime_ring;
 mod state;
 mod strike;
 mod wall;
@@ -33,19 +37,24 @@ impl Plugin for DemonBossPlugin {
             earth_prison::DemonBossEarthPrisonPlugin,
             wall::DemonBossWallPlugin,
             win_item::WinItemPlugin,
+            phase::DemonBossPhasePlugin,
+        ))
+        .add_plugins((
+            slime_ring::DemonBossSlimeRingPlugin,
+            meteor::DemonBossMeteorPlugin,
+            flame_line::DemonBossFlameLinePlugin,
         ));
     }
 }
 
-const MOVE_SPEED: f32 = 50.0;
-const STRIKE_RANGE: f32 = 125.0;
-const INV_CAST_RANGE: f32 = 300.0;
-
 #[derive(Component)]
 pub struct DemonBoss {
-    pub damage: f32,
     rage: DemonBossRage,
     state: DemonBossState,
+    /// Index into `DemonBossPhases`.
+    phase: usize,
+    /// The last two casted spells, the oldest first.
+    recent_spells: [Option<cast::DemonSpell>; 2],
 }
 
 #[derive(Component)]
@@ -68,13 +77,14 @@ impl DemonBossRage {
 impl Default for DemonBoss {
     fn default() -> Self {
         Self {
-            damage: 2.0,
             rage: DemonBossRage {
                 active: false,
                 rage_stack: 0,
                 timer: Timer::from_seconds(10.0, TimerMode::Repeating),
             },
             state: DemonBossState::Idling,
+            phase: 0,
+            recent_spells: [None; 2],
         }
     }
 }
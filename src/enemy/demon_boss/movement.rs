@@ -1,27 +1,92 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::{player::Player, GameState};
+use crate::{
+    player::Player,
+    spell::status::{StatusEffects, StatusKind},
+    world::navigation::{cell_from_pos, NavGrid},
+    GameState,
+};
+
+use super::{phase::DemonBossPhases, DemonBoss, DemonBossState};
 
-use super::{DemonBoss, DemonBossState, MOVE_SPEED};
+/// Seconds until the path to the player is searched again, even if the player stayed in its cell.
+const REPATH_TIME: f32 = 0.5;
 
 #[derive(Component, Deref, DerefMut)]
 pub struct MovementCooldownTimer(pub Timer);
 
+/// The last path to the player, so that the boss doesn't search a new one every frame.
+#[derive(Component)]
+pub struct DemonBossPath {
+    /// `None` if there is no search yet, e.g. because the player was in sight.
+    waypoints: Option<Vec<Vec2>>,
+    player_cell: IVec2,
+    timer: Timer,
+}
+
+impl Default for DemonBossPath {
+    fn default() -> Self {
+        Self {
+            waypoints: None,
+            player_cell: IVec2::ZERO,
+            timer: Timer::from_seconds(REPATH_TIME, TimerMode::Once),
+        }
+    }
+}
+
+impl DemonBossPath {
+    fn direction(&mut self, nav_grid: &NavGrid, delta: Duration, from: Vec2, to: Vec2) -> Vec2 {
+        let straight = (to - from).normalize_or_zero();
+        if nav_grid.line_of_sight(from, to) {
+            self.waypoints = None;
+            return straight;
+        }
+
+        self.timer.tick(delta);
+        let player_cell = cell_from_pos(to);
+        if self.waypoints.is_none() || self.timer.finished() || player_cell != self.player_cell {
+            self.waypoints = Some(nav_grid.find_path(from, to).unwrap_or_default());
+            self.player_cell = player_cell;
+            self.timer.reset();
+        }
+
+        self.waypoints
+            .as_deref()
+            .and_then(|waypoints| nav_grid.follow_path(from, waypoints))
+            .unwrap_or(straight)
+    }
+}
+
 fn movement(
-    mut q_demon_boss: Query<(&Transform, &mut Velocity, &DemonBoss)>,
+    time: Res<Time>,
+    phases: Res<DemonBossPhases>,
+    nav_grid: Res<NavGrid>,
+    mut q_demon_boss: Query<(
+        &Transform,
+        &mut Velocity,
+        &mut DemonBossPath,
+        &DemonBoss,
+        &StatusEffects,
+    )>,
     q_player: Query<&Transform, (With<Player>, Without<DemonBoss>)>,
 ) {
-    let (demon_boss_transform, mut velocity, demon_boss) = match q_demon_boss.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
+    let (demon_boss_transform, mut velocity, mut path, demon_boss, status_effects) =
+        match q_demon_boss.single_mut() {
+            Ok(p) => p,
+            Err(_) => return,
+        };
     let player_transform = match q_player.single() {
         Ok(p) => p,
         Err(_) => return,
     };
 
-    if demon_boss.state == DemonBossState::Staggering {
+    if demon_boss.state == DemonBossState::Staggering
+        || demon_boss.state == DemonBossState::Transitioning
+        || status_effects.has(StatusKind::KnockedBack)
+    {
         return;
     }
     if demon_boss.state != DemonBossState::Moving {
@@ -29,11 +94,14 @@ fn movement(
         return;
     }
 
-    let direction = (player_transform.translation - demon_boss_transform.translation)
-        .truncate()
-        .normalize_or_zero();
+    let direction = path.direction(
+        &nav_grid,
+        time.delta(),
+        demon_boss_transform.translation.truncate(),
+        player_transform.translation.truncate(),
+    );
     let mul = if demon_boss.rage.active { 2.0 } else { 1.0 };
-    velocity.linvel = direction * mul * MOVE_SPEED;
+    velocity.linvel = direction * mul * phases.get(demon_boss).move_speed * status_effects.slow();
 }
 
 fn despawn_cooldowns(
@@ -0,0 +1,186 @@
+use serde::Deserialize;
+
+use bevy::asset::LoadContext;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use bevy_trickfilm::prelude::*;
+
+use crate::spell::invulnerable::Invulnerable;
+use crate::ui::health::Health;
+use crate::utils::ron_asset::{RonAsset, RonAssetLoader};
+use crate::world::CameraShake;
+use crate::{GameAssets, GameState};
+
+use super::{cast::DemonSpell, state::DemonBossState, DemonBoss};
+
+/// How much slower than a normal stagger the transition animation plays.
+pub const TRANSITION_ANIMATION_SPEED: f32 = 0.35;
+const TRANSITION_TRAUMA: f32 = 0.8;
+const TRANSITION_INVULNERABILITY: f32 = 2.0;
+
+#[derive(Deserialize, Clone)]
+pub struct WeightedSpell {
+    pub spell: DemonSpell,
+    pub weight: f32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct DemonBossPhase {
+    /// The phase starts once the health drops to this fraction of the max health.
+    pub health: f32,
+    pub move_speed: f32,
+    pub strike_range: f32,
+    /// Casts a spell when the player is further away than this.
+    pub cast_range: f32,
+    pub strike_cooldown: f32,
+    pub spell_cooldown: f32,
+    pub cast_time: f32,
+    /// Casts a spell regardless of the distance when it didn't cast one for this long.
+    pub last_spell_time: f32,
+    pub spells: Vec<WeightedSpell>,
+    #[serde(default)]
+    pub permanent_rage: bool,
+}
+
+impl Default for DemonBossPhase {
+    fn default() -> Self {
+        Self {
+            health: 1.0,
+            move_speed: 50.0,
+            strike_range: 125.0,
+            cast_range: 300.0,
+            strike_cooldown: 5.0,
+            spell_cooldown: 5.0,
+            cast_time: 1.5,
+            last_spell_time: 10.0,
+            spells: vec![
+                WeightedSpell {
+                    spell: DemonSpell::Explosion,
+                    weight: 1.0,
+                },
+                WeightedSpell {
+                    spell: DemonSpell::EarthPrison,
+                    weight: 1.0,
+                },
+            ],
+            permanent_rage: false,
+        }
+    }
+}
+
+/// The phases of the demon boss, loaded from `assets/enemy/demon_boss.phases.ron`.
+/// Sorted by descending `health`, there is always at least one phase.
+#[derive(Asset, Resource, TypePath, Clone)]
+pub struct DemonBossPhases {
+    phases: Vec<DemonBossPhase>,
+}
+
+impl DemonBossPhases {
+    pub fn first(&self) -> &DemonBossPhase {
+        &self.phases[0]
+    }
+
+    pub fn get(&self, demon_boss: &DemonBoss) -> &DemonBossPhase {
+        &self.phases[demon_boss.phase.min(self.phases.len() - 1)]
+    }
+}
+
+impl FromWorld for DemonBossPhases {
+    fn from_world(world: &mut World) -> Self {
+        let handle = world.resource::<GameAssets>().demon_boss_phases.clone();
+        match world.resource::<Assets<DemonBossPhases>>().get(&handle) {
+            Some(phases) => phases.clone(),
+            None => {
+                error!("demon boss phases asset is not loaded, falling back to a single phase");
+                DemonBossPhases {
+                    phases: vec![DemonBossPhase::default()],
+                }
+            }
+        }
+    }
+}
+
+impl RonAsset for DemonBossPhases {
+    type Def = Vec<DemonBossPhase>;
+
+    fn extensions() -> &'static [&'static str] {
+        &["phases.ron"]
+    }
+
+    fn from_def(mut phases: Self::Def, _load_context: &mut LoadContext<'_>) -> Self {
+        phases.sort_by(|a, b| b.health.total_cmp(&a.health));
+        if phases.is_empty() {
+            phases.push(DemonBossPhase::default());
+        }
+        DemonBossPhases { phases }
+    }
+}
+
+fn advance_phase(
+    mut commands: Commands,
+    phases: Res<DemonBossPhases>,
+    mut camera_shake: ResMut<CameraShake>,
+    mut q_demon_boss: Query<(
+        Entity,
+        &mut DemonBoss,
+        &mut AnimationPlayer2D,
+        &mut Velocity,
+        &Health,
+    )>,
+) {
+    let (entity, mut demon_boss, mut animator, mut velocity, health) =
+        match q_demon_boss.single_mut() {
+            Ok(r) => r,
+            Err(_) => return,
+        };
+    if demon_boss.state == DemonBossState::Dying || health.health <= 0.0 {
+        return;
+    }
+
+    // A big hit can skip phases, in that case go straight to the last one.
+    let fraction = health.health / health.max_health;
+    let phase = match phases.phases.iter().rposition(|p| fraction <= p.health) {
+        Some(p) => p,
+        None => return,
+    };
+    if phase <= demon_boss.phase {
+        return;
+    }
+
+    demon_boss.phase = phase;
+    demon_boss.state = DemonBossState::Transitioning;
+    // `movement` leaves the boss alone while transitioning, it would slide the whole time otherwise.
+    velocity.linvel = Vec2::ZERO;
+    // The boss might already be staggering, which uses the same clip.
+    animator.replay();
+    camera_shake.add_trauma(TRANSITION_TRAUMA);
+    commands
+        .entity(entity)
+        .insert(Invulnerable::new(TRANSITION_INVULNERABILITY).without_blink());
+}
+
+fn apply_permanent_rage(phases: Res<DemonBossPhases>, mut q_demon_boss: Query<&mut DemonBoss>) {
+    let mut demon_boss = match q_demon_boss.single_mut() {
+        Ok(r) => r,
+        Err(_) => return,
+    };
+
+    if phases.get(&demon_boss).permanent_rage && !demon_boss.rage.active {
+        demon_boss.rage.active = true;
+    }
+}
+
+pub struct DemonBossPhasePlugin;
+
+impl Plugin for DemonBossPhasePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<DemonBossPhases>()
+            .init_asset_loader::<RonAssetLoader<DemonBossPhases>>()
+            .add_systems(
+                Update,
+                (advance_phase, apply_permanent_rage)
+                    .chain()
+                    .run_if(in_state(GameState::Gaming)),
+            );
+    }
+}
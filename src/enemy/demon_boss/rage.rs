@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::{world::camera::YSort, GameAssets, GameState};
 
-use super::{state::DemonBossState, DemonBoss};
+use super::{phase::DemonBossPhases, state::DemonBossState, DemonBoss};
 
 #[derive(Component)]
 pub struct RageAura;
@@ -58,12 +58,16 @@ fn despawn_rage_aura(
     }
 }
 
-fn tick_timer(time: Res<Time>, mut q_demon_boss: Query<&mut DemonBoss>) {
+fn tick_timer(
+    time: Res<Time>,
+    phases: Res<DemonBossPhases>,
+    mut q_demon_boss: Query<&mut DemonBoss>,
+) {
     let mut demon_boss = match q_demon_boss.single_mut() {
         Ok(r) => r,
         Err(_) => return,
     };
-    if !demon_boss.rage.active {
+    if !demon_boss.rage.active || phases.get(&demon_boss).permanent_rage {
         return;
     }
 
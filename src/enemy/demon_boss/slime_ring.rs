@@ -0,0 +1,53 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::enemy::{EnemyKind, EnemyModifiers, SpawnEnemy};
+use crate::{player::Player, world::navigation::NavGrid, GameState};
+
+use super::cast::{DemonSpell, SpawnDemonSpell};
+
+const COUNT: usize = 6;
+const RADIUS: f32 = 150.0;
+/// No ring is summoned while this many enemies are alive, see `DemonSpell::situational_weight`.
+pub const MAX_SUMMONS: usize = 8;
+
+fn spawn_slime_ring(
+    nav_grid: Res<NavGrid>,
+    q_player: Query<&Transform, With<Player>>,
+    mut ev_spawn_demon_spells: EventReader<SpawnDemonSpell>,
+    mut ev_spawn_enemy: EventWriter<SpawnEnemy>,
+) {
+    let player_pos = match q_player.single() {
+        Ok(r) => r.translation,
+        Err(_) => return,
+    };
+
+    for ev in ev_spawn_demon_spells.read() {
+        if ev.spell != DemonSpell::SlimeRing {
+            continue;
+        }
+
+        for i in 0..COUNT {
+            let offset = Vec2::from_angle(TAU * i as f32 / COUNT as f32) * RADIUS;
+            let pos = player_pos + offset.extend(0.0);
+            if nav_grid.is_blocked(pos.truncate()) {
+                continue;
+            }
+
+            ev_spawn_enemy.write(SpawnEnemy {
+                kind: EnemyKind::Slime,
+                pos,
+                modifiers: EnemyModifiers::default(),
+            });
+        }
+    }
+}
+
+pub struct DemonBossSlimeRingPlugin;
+
+impl Plugin for DemonBossSlimeRingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spawn_slime_ring.run_if(in_state(GameState::Gaming)));
+    }
+}
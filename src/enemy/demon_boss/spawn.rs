@@ -6,17 +6,22 @@ use crate::{
     enemy::Enemy,
     item::platform::TriggerFinalAct,
     player::PLAYER_SPAWN_POS,
+    spell::damage::{DamageType, Hitbox, Hurtbox, Knockback, Resistances},
+    spell::Faction,
     ui::health::Health,
     world::camera::{YSort, TRANSLATION_TO_PIXEL},
     GameAssets, GameState,
 };
 
 use super::{
-    audio::DemonBossStepsTimer, state::DemonBossState, strike::DemonBossStrike, DemonBoss,
+    audio::DemonBossStepsTimer, movement::DemonBossPath, state::DemonBossState,
+    strike::DemonBossStrike, DemonBoss,
 };
 
 const SCALE: f32 = 1.5;
 const SPAWN_POS: Vec3 = Vec3::new(200.0, 0.0, 0.0);
+const DAMAGE: f32 = 2.0;
+const KNOCKBACK: f32 = 200.0;
 
 #[derive(Component)]
 struct Shadow;
@@ -94,6 +99,16 @@ fn spawn_demon_boss(
             Collider::ball(25.0),
             CollisionGroups::default(),
             ActiveEvents::COLLISION_EVENTS,
+            Hurtbox {
+                knockback: 0.5,
+                ..default()
+            },
+            Hitbox {
+                damage: DAMAGE,
+                damage_type: DamageType::Physical,
+                knockback: Knockback::Radial(KNOCKBACK),
+                faction: Faction::Enemy,
+            },
             Transform::from_translation(Vec3::new(0.0, -30.0, 0.0)),
         ))
         .id();
@@ -107,8 +122,14 @@ fn spawn_demon_boss(
             DemonBoss::default(),
             DemonBossStepsTimer::default(),
             DemonBossStrike::default(),
-            Enemy { damage: 0.0 },
-            Health::new(100.0),
+            DemonBossPath::default(),
+            Enemy,
+            Health::new(100.0).with_armor(0.5),
+            Resistances {
+                fire: 0.75,
+                void: 0.5,
+                ..default()
+            },
             animator,
             YSort(36.0 * SCALE * TRANSLATION_TO_PIXEL),
             Sprite::from_atlas_image(
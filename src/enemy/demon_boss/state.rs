@@ -1,13 +1,18 @@
 use bevy::prelude::*;
 use bevy_trickfilm::prelude::*;
 
-use crate::{player::Player, GameAssets, GameState};
+use crate::{
+    player::Player,
+    spell::status::{StatusEffects, StatusKind},
+    GameAssets, GameState,
+};
 
 use super::{
     cast::{DemonSpellCooldown, LastSpellTimer, SpawnDemonSpell},
     movement::MovementCooldownTimer,
+    phase::{DemonBossPhases, TRANSITION_ANIMATION_SPEED},
     strike::StrikeCooldown,
-    DemonBoss, INV_CAST_RANGE, STRIKE_RANGE,
+    DemonBoss,
 };
 
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
@@ -18,6 +23,8 @@ pub enum DemonBossState {
     Moving,
     Striking,
     Staggering,
+    /// Switching to the next phase, see `DemonBossPhases`.
+    Transitioning,
     Dying,
 }
 
@@ -35,7 +42,9 @@ fn update_animation(
         DemonBossState::Casting => (assets.demon_boss_animations[1].clone(), true),
         DemonBossState::Moving => (assets.demon_boss_animations[2].clone(), true),
         DemonBossState::Striking => (assets.demon_boss_animations[3].clone(), false),
-        DemonBossState::Staggering => (assets.demon_boss_animations[4].clone(), false),
+        DemonBossState::Staggering | DemonBossState::Transitioning => {
+            (assets.demon_boss_animations[4].clone(), false)
+        }
         DemonBossState::Dying => (assets.demon_boss_animations[5].clone(), false),
     };
 
@@ -44,6 +53,13 @@ fn update_animation(
     } else {
         animator.play(clip);
     }
+
+    let speed = if demon_boss.state == DemonBossState::Transitioning {
+        TRANSITION_ANIMATION_SPEED
+    } else {
+        1.0
+    };
+    animator.set_speed(speed);
 }
 
 fn adjust_sprite_flip(
@@ -61,6 +77,7 @@ fn adjust_sprite_flip(
 
     if demon_boss.state == DemonBossState::Striking
         || demon_boss.state == DemonBossState::Staggering
+        || demon_boss.state == DemonBossState::Transitioning
     {
         return;
     }
@@ -116,7 +133,9 @@ fn staggering_to_idle(
         Ok(r) => r,
         Err(_) => return,
     };
-    if demon_boss.state != DemonBossState::Staggering {
+    if demon_boss.state != DemonBossState::Staggering
+        && demon_boss.state != DemonBossState::Transitioning
+    {
         return;
     }
 
@@ -131,6 +150,7 @@ fn staggering_to_idle(
 }
 
 fn switch_to_striking(
+    phases: Res<DemonBossPhases>,
     mut q_demon_boss: Query<(&Transform, &mut DemonBoss)>,
     q_player: Query<&Transform, (With<Player>, Without<DemonBoss>)>,
     q_strike_cooldowns: Query<&StrikeCooldown>,
@@ -149,6 +169,7 @@ fn switch_to_striking(
 
     if demon_boss.state == DemonBossState::Casting
         || demon_boss.state == DemonBossState::Striking
+        || demon_boss.state == DemonBossState::Transitioning
         || demon_boss.state == DemonBossState::Dying
     {
         return;
@@ -157,7 +178,7 @@ fn switch_to_striking(
     let dis = player_pos
         .truncate()
         .distance_squared(demon_boss_transform.translation.truncate());
-    let strike_range = STRIKE_RANGE.powi(2);
+    let strike_range = phases.get(&demon_boss).strike_range.powi(2);
 
     if dis <= strike_range {
         demon_boss.state = DemonBossState::Striking;
@@ -165,6 +186,7 @@ fn switch_to_striking(
 }
 
 fn switch_to_casting(
+    phases: Res<DemonBossPhases>,
     mut q_demon_boss: Query<(&Transform, &mut DemonBoss)>,
     q_player: Query<&Transform, (With<Player>, Without<DemonBoss>)>,
     q_demon_spell_cooldown: Query<&DemonSpellCooldown>,
@@ -188,6 +210,7 @@ fn switch_to_casting(
 
     if demon_boss.state == DemonBossState::Casting
         || demon_boss.state == DemonBossState::Striking
+        || demon_boss.state == DemonBossState::Transitioning
         || demon_boss.state == DemonBossState::Dying
     {
         return;
@@ -196,7 +219,7 @@ fn switch_to_casting(
     let dis = player_pos
         .truncate()
         .distance_squared(demon_boss_transform.translation.truncate());
-    let inv_cast_range = INV_CAST_RANGE.powi(2);
+    let inv_cast_range = phases.get(&demon_boss).cast_range.powi(2);
 
     if dis >= inv_cast_range || last_spell_timer.finished() {
         demon_boss.state = DemonBossState::Casting;
@@ -222,6 +245,12 @@ fn switch_to_moving(
     demon_boss.state = DemonBossState::Moving;
 }
 
+fn not_shocked(q_demon_boss: Query<&StatusEffects, With<DemonBoss>>) -> bool {
+    q_demon_boss
+        .single()
+        .is_ok_and(|status_effects| !status_effects.has(StatusKind::Shocked))
+}
+
 pub struct DemonBossStatePlugin;
 
 impl Plugin for DemonBossStatePlugin {
@@ -231,12 +260,16 @@ impl Plugin for DemonBossStatePlugin {
             (
                 update_animation,
                 adjust_sprite_flip,
-                striking_to_idle,
-                casting_to_idle,
-                staggering_to_idle,
-                switch_to_striking,
-                switch_to_casting,
-                switch_to_moving,
+                (
+                    striking_to_idle,
+                    casting_to_idle,
+                    staggering_to_idle,
+                    switch_to_striking,
+                    switch_to_casting,
+                    switch_to_moving,
+                )
+                    .chain()
+                    .run_if(not_shocked),
             )
                 .chain()
                 .run_if(in_state(GameState::Gaming)),
@@ -3,7 +3,7 @@ use bevy_trickfilm::prelude::*;
 
 use crate::GameState;
 
-use super::{DemonBoss, DemonBossState};
+use super::{phase::DemonBossPhases, DemonBoss, DemonBossState};
 
 const STRIKE_HITBOX_START: f32 = 1.2;
 const STRIKE_HITBOX_TIME: f32 = 0.2;
@@ -21,6 +21,7 @@ pub struct StrikeCooldown {
 
 fn spawn_strike_cooldown(
     mut commands: Commands,
+    phases: Res<DemonBossPhases>,
     q_demon_boss: Query<&DemonBoss>,
     q_strike_cooldowns: Query<&StrikeCooldown>,
 ) {
@@ -36,7 +37,7 @@ fn spawn_strike_cooldown(
     }
 
     commands.spawn(StrikeCooldown {
-        timer: Timer::from_seconds(5.0, TimerMode::Once),
+        timer: Timer::from_seconds(phases.get(demon_boss).strike_cooldown, TimerMode::Once),
     });
 }
 
@@ -0,0 +1,182 @@
+use rand::{rngs::ThreadRng, Rng};
+
+use bevy::prelude::*;
+
+use super::demon_boss::explosion::spawn_normal_explosion;
+use super::SpeedMultiplier;
+use crate::spell::damage::{apply_damage, DamageDealt};
+use crate::ui::health::Health;
+use crate::world::camera::YSort;
+use crate::{GameAssets, GameState};
+
+const SHIELD: f32 = 5.0;
+const SHIELD_TIME: f32 = 60.0;
+const HASTE: f32 = 1.5;
+const REGENERATION: f32 = 1.0;
+const REGENERATION_INTERVAL: f32 = 1.5;
+/// Multiplier for the dealt damage that vampiric elites heal.
+const LIFESTEAL: f32 = 2.0;
+const AURA_SCALE: f32 = 0.35;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EliteModifier {
+    Shielded,
+    Hasted,
+    Regenerating,
+    /// Leaves an explosion behind when it dies.
+    Explosive,
+    /// Heals when it hits something.
+    Vampiric,
+}
+
+impl EliteModifier {
+    fn aura_color(&self) -> Color {
+        match self {
+            EliteModifier::Shielded => Color::srgba(0.45, 0.7, 1.0, 0.7),
+            EliteModifier::Hasted => Color::srgba(1.0, 1.0, 0.45, 0.7),
+            EliteModifier::Regenerating => Color::srgba(0.45, 1.0, 0.5, 0.7),
+            EliteModifier::Explosive => Color::srgba(1.0, 0.5, 0.2, 0.7),
+            EliteModifier::Vampiric => Color::srgba(0.8, 0.1, 0.25, 0.7),
+        }
+    }
+}
+
+/// Every spawned enemy has a `chance` to become an elite with a random modifier of `modifiers`.
+#[derive(Clone, Default)]
+pub struct EliteRoll {
+    pub chance: f32,
+    pub modifiers: Vec<EliteModifier>,
+}
+
+impl EliteRoll {
+    pub fn roll(&self, rng: &mut ThreadRng) -> Option<EliteModifier> {
+        if self.modifiers.is_empty() || !rng.gen_bool(self.chance.clamp(0.0, 1.0) as f64) {
+            return None;
+        }
+        Some(self.modifiers[rng.gen_range(0..self.modifiers.len())])
+    }
+}
+
+/// Inserted by the spawn systems of the enemies, see `EnemyModifiers`.
+#[derive(Component)]
+pub struct Elite {
+    modifier: EliteModifier,
+    regeneration_timer: Timer,
+    exploded: bool,
+}
+
+impl Elite {
+    pub fn new(modifier: EliteModifier) -> Self {
+        Self {
+            modifier,
+            regeneration_timer: Timer::from_seconds(REGENERATION_INTERVAL, TimerMode::Repeating),
+            exploded: false,
+        }
+    }
+}
+
+#[derive(Component)]
+struct EliteAura;
+
+fn apply_elites(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_elites: Query<(Entity, &Elite, &mut Health, &mut SpeedMultiplier), Added<Elite>>,
+) {
+    for (entity, elite, mut health, mut speed) in &mut q_elites {
+        match elite.modifier {
+            EliteModifier::Shielded => health.add_shield(SHIELD, SHIELD_TIME),
+            EliteModifier::Hasted => **speed *= HASTE,
+            EliteModifier::Regenerating | EliteModifier::Explosive | EliteModifier::Vampiric => {}
+        }
+
+        let mut sprite = Sprite::from_image(assets.demon_boss_aura.clone());
+        sprite.color = elite.modifier.aura_color();
+        let aura = commands
+            .spawn((
+                EliteAura,
+                YSort(-0.5),
+                sprite,
+                Transform::from_scale(Vec3::splat(AURA_SCALE)),
+            ))
+            .id();
+        commands.entity(entity).add_children(&[aura]);
+    }
+}
+
+fn despawn_elite_auras(
+    mut commands: Commands,
+    q_elites: Query<&Health, With<Elite>>,
+    q_auras: Query<(Entity, &ChildOf), With<EliteAura>>,
+) {
+    for (entity, child_of) in &q_auras {
+        if q_elites
+            .get(child_of.parent())
+            .is_ok_and(|health| health.health <= 0.0)
+        {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn regenerate_elites(time: Res<Time>, mut q_elites: Query<(&mut Elite, &mut Health)>) {
+    for (mut elite, mut health) in &mut q_elites {
+        if elite.modifier != EliteModifier::Regenerating || health.health <= 0.0 {
+            continue;
+        }
+
+        elite.regeneration_timer.tick(time.delta());
+        if elite.regeneration_timer.just_finished() {
+            health.heal(REGENERATION);
+        }
+    }
+}
+
+fn explode_elites(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_elites: Query<(&Transform, &mut Elite, &Health)>,
+) {
+    for (transform, mut elite, health) in &mut q_elites {
+        if elite.modifier != EliteModifier::Explosive || elite.exploded || health.health > 0.0 {
+            continue;
+        }
+
+        elite.exploded = true;
+        spawn_normal_explosion(&mut commands, &assets, transform.translation);
+    }
+}
+
+fn steal_life(
+    mut q_elites: Query<(&Elite, &mut Health)>,
+    mut ev_damage_dealt: EventReader<DamageDealt>,
+) {
+    for ev in ev_damage_dealt.read() {
+        let (elite, mut health) = match q_elites.get_mut(ev.instigator) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        if elite.modifier == EliteModifier::Vampiric && health.health > 0.0 {
+            health.heal(ev.amount * LIFESTEAL);
+        }
+    }
+}
+
+pub struct ElitePlugin;
+
+impl Plugin for ElitePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                apply_elites,
+                despawn_elite_auras,
+                regenerate_elites,
+                explode_elites,
+                steal_life.after(apply_damage),
+            )
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -1,17 +1,111 @@
 pub mod demon_boss;
+pub mod elite;
 pub mod slime;
+pub mod slime_caster;
+pub mod steering;
 
 use bevy::prelude::*;
+use bevy_kira_audio::prelude::AudioSource;
+
+use crate::audio::PlaySound;
+use crate::spell::damage::{apply_damage, DamageDealt, Resistances};
+use crate::spell::status::StatusEffects;
+use crate::spell::Faction;
+use crate::ui::health::Health;
+
+use elite::EliteModifier;
+use steering::Steering;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((slime::EnemySlimePlugin, demon_boss::DemonBossPlugin));
+        app.add_plugins((
+            slime::EnemySlimePlugin,
+            slime_caster::SlimeCasterPlugin,
+            demon_boss::DemonBossPlugin,
+            elite::ElitePlugin,
+            steering::SteeringPlugin,
+        ))
+        .add_event::<SpawnEnemy>()
+        .add_systems(Update, play_hit_sounds.after(apply_damage));
+    }
+}
+
+/// Everything that can be spawned with `SpawnEnemy`, each kind is handled by its own plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnemyKind {
+    #[default]
+    Slime,
+    /// Splits into small slimes when it dies.
+    LargeSlime,
+    /// Keeps its distance and lobs slime balls at the player.
+    SlimeCaster,
+}
+
+/// Applied to enemies when they are spawned.
+#[derive(Clone, Copy)]
+pub struct EnemyModifiers {
+    pub resistances: Resistances,
+    /// Multiplier for the health of the enemy.
+    pub health: f32,
+    pub elite: Option<EliteModifier>,
+}
+
+impl Default for EnemyModifiers {
+    fn default() -> Self {
+        Self {
+            resistances: Resistances::default(),
+            health: 1.0,
+            elite: None,
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct SpawnEnemy {
+    pub kind: EnemyKind,
+    pub pos: Vec3,
+    pub modifiers: EnemyModifiers,
+}
+
+#[derive(Component)]
+#[require(Faction = Faction::Enemy, Resistances, SpeedMultiplier, Steering, StatusEffects)]
+pub struct Enemy;
+
+/// Multiplier for the movement speed and the cooldowns of an enemy.
+#[derive(Component, Deref, DerefMut)]
+pub struct SpeedMultiplier(pub f32);
+
+impl Default for SpeedMultiplier {
+    fn default() -> Self {
+        Self(1.0)
     }
 }
 
+/// Played whenever the enemy takes damage and survives it.
 #[derive(Component)]
-pub struct Enemy {
-    pub damage: f32,
+pub struct HitSound(pub Handle<AudioSource>);
+
+fn play_hit_sounds(
+    q_enemies: Query<(&HitSound, &Health)>,
+    mut ev_damage_dealt: EventReader<DamageDealt>,
+    mut ev_play_sound: EventWriter<PlaySound>,
+) {
+    for ev in ev_damage_dealt.read() {
+        let (hit_sound, health) = match q_enemies.get(ev.target) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        // Dying enemies play their death sound instead.
+        if health.health <= 0.0 {
+            continue;
+        }
+
+        ev_play_sound.write(PlaySound {
+            clip: hit_sound.0.clone(),
+            parent: Some(ev.target),
+            ..default()
+        });
+    }
 }
@@ -5,17 +5,26 @@ mod sprite;
 
 use bevy::prelude::*;
 
-use super::Enemy;
+use super::steering::{steer_enemies, Steering};
+use super::{Enemy, SpeedMultiplier};
 use crate::audio::PlaySound;
+use crate::player::Player;
+use crate::spell::status::{StatusEffects, StatusKind};
+use crate::world::navigation::NavGrid;
 use crate::{GameAssets, GameState};
 
 const MAX_JUMP_SPEED: f32 = 200.0;
 const RANDOM_OFFSET_INTENSITY: f32 = 0.25;
 const JUMP_TIME: f32 = 0.5;
 
+const JUMP_COOLDOWN: f32 = 3.5;
+
 const STAGGERING_TIME: f32 = 0.2;
 const STAGGERING_INTENSITY: f32 = 100.0;
 
+/// How many small slimes a large slime splits into.
+const SPLIT_COUNT: usize = 3;
+
 pub struct EnemySlimePlugin;
 
 impl Plugin for EnemySlimePlugin {
@@ -26,10 +35,10 @@ impl Plugin for EnemySlimePlugin {
             sprite::SlimeSpritePlugin,
             collision::SlimeCollisionPlugin,
         ))
-        .add_event::<SpawnSlimeEnemy>()
         .add_systems(
             Update,
-            (change_slime_states)
+            change_slime_states
+                .after(steer_enemies)
                 .run_if(in_state(GameState::Gaming).or(in_state(GameState::GameOver))),
         );
     }
@@ -44,33 +53,73 @@ pub enum SlimeState {
     Dying,
 }
 
+/// Large slimes split into small ones when they die, see `split_slimes`.
+#[derive(Default, PartialEq, Clone, Copy)]
+pub enum SlimeSize {
+    Small,
+    #[default]
+    Normal,
+    Large,
+}
+
+impl SlimeSize {
+    fn scale(&self) -> f32 {
+        match self {
+            SlimeSize::Small => 1.0,
+            SlimeSize::Normal => 1.5,
+            SlimeSize::Large => 2.25,
+        }
+    }
+
+    /// Multiplier for the base health.
+    fn health(&self) -> f32 {
+        match self {
+            SlimeSize::Small => 0.4,
+            SlimeSize::Normal => 1.0,
+            SlimeSize::Large => 2.5,
+        }
+    }
+
+    /// Multiplier for the jump speed, also shortens the jump cooldown.
+    fn speed(&self) -> f32 {
+        match self {
+            SlimeSize::Small => 1.4,
+            SlimeSize::Normal => 1.0,
+            SlimeSize::Large => 0.8,
+        }
+    }
+}
+
 #[derive(Component)]
 struct SlimeEnemy {
     state: SlimeState,
+    size: SlimeSize,
     jump_speed: f32,
     jump_direction: Vec2,
     jumping_timer: Timer,
     jump_cooldown_timer: Timer,
     death_timer: Timer,
     staggering_timer: Timer,
+    /// The knockback of the last hit, passed on to the small slimes when splitting.
+    knockback: Vec2,
     disabled: bool,
 }
 
-#[derive(Event)]
-pub struct SpawnSlimeEnemy {
-    pub pos: Vec3,
-}
-
-impl Default for SlimeEnemy {
-    fn default() -> Self {
+impl SlimeEnemy {
+    fn new(size: SlimeSize) -> Self {
         Self {
             state: SlimeState::Idling,
-            jump_speed: MAX_JUMP_SPEED,
+            size,
+            jump_speed: MAX_JUMP_SPEED * size.speed(),
             jump_direction: Vec2::ZERO,
             jumping_timer: Timer::from_seconds(JUMP_TIME, TimerMode::Repeating),
-            jump_cooldown_timer: Timer::from_seconds(3.5, TimerMode::Repeating),
+            jump_cooldown_timer: Timer::from_seconds(
+                JUMP_COOLDOWN / size.speed(),
+                TimerMode::Repeating,
+            ),
             death_timer: Timer::from_seconds(0.070 * 6.0, TimerMode::Once),
             staggering_timer: Timer::from_seconds(STAGGERING_TIME, TimerMode::Repeating),
+            knockback: Vec2::ZERO,
             disabled: false,
         }
     }
@@ -79,13 +128,34 @@ impl Default for SlimeEnemy {
 fn change_slime_states(
     assets: Res<GameAssets>,
     time: Res<Time>,
-    mut q_slimes: Query<(Entity, &mut SlimeEnemy), With<Enemy>>,
+    nav_grid: Res<NavGrid>,
+    q_player: Query<&Transform, With<Player>>,
+    mut q_slimes: Query<
+        (
+            Entity,
+            &Transform,
+            &mut SlimeEnemy,
+            &StatusEffects,
+            &SpeedMultiplier,
+            &Steering,
+        ),
+        (With<Enemy>, Without<Player>),
+    >,
     mut ev_play_sound: EventWriter<PlaySound>,
 ) {
-    for (entity, mut slime) in &mut q_slimes {
+    let player_pos = q_player.single().ok().map(|t| t.translation.truncate());
+
+    for (entity, transform, mut slime, status_effects, speed, steering) in &mut q_slimes {
+        if slime.state != SlimeState::Dying && status_effects.has(StatusKind::Shocked) {
+            continue;
+        }
+
         match slime.state {
             SlimeState::Idling => {
-                slime.jump_cooldown_timer.tick(time.delta());
+                // Frozen slimes take longer to get ready for the next jump.
+                slime
+                    .jump_cooldown_timer
+                    .tick(time.delta().mul_f32(status_effects.slow() * **speed));
                 if slime.jump_cooldown_timer.just_finished() {
                     ev_play_sound.write(PlaySound {
                         clip: assets.slime_jump_sound.clone(),
@@ -94,6 +164,15 @@ fn change_slime_states(
                         parent: Some(entity),
                         ..default()
                     });
+                    if let Some(player_pos) = player_pos {
+                        movement::aim_jump(
+                            &nav_grid,
+                            &mut slime,
+                            transform.translation.truncate(),
+                            player_pos,
+                            **steering,
+                        );
+                    }
                     slime.state = SlimeState::Jumping;
                 }
             }
@@ -112,11 +191,6 @@ fn change_slime_states(
             SlimeState::Staggering => {
                 slime.staggering_timer.tick(time.delta());
                 if slime.staggering_timer.just_finished() {
-                    ev_play_sound.write(PlaySound {
-                        clip: assets.slime_hit_sound.clone(),
-                        parent: Some(entity),
-                        ..default()
-                    });
                     slime.state = SlimeState::Idling;
                 }
             }
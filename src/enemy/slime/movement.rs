@@ -3,50 +3,58 @@ use rand::{self, Rng};
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use super::{Enemy, SlimeEnemy, SlimeState, JUMP_TIME, MAX_JUMP_SPEED, RANDOM_OFFSET_INTENSITY};
-use crate::player::Player;
+use super::{SlimeEnemy, SlimeState, JUMP_TIME, MAX_JUMP_SPEED, RANDOM_OFFSET_INTENSITY};
+use crate::enemy::steering::{steer_enemies, Steering};
+use crate::enemy::SpeedMultiplier;
+use crate::spell::status::{StatusEffects, StatusKind};
+use crate::world::navigation::NavGrid;
 
-fn update_jump_position(
-    mut q_slimes: Query<(&Transform, &mut SlimeEnemy), (With<Enemy>, Without<Player>)>,
-    q_player: Query<&Transform, With<Player>>,
+const SEPARATION_SPEED: f32 = 30.0;
+
+/// Pick the direction and speed of the next jump, called once when the jump starts
+/// so that the path only needs to be searched once per jump.
+pub(super) fn aim_jump(
+    nav_grid: &NavGrid,
+    slime: &mut SlimeEnemy,
+    from: Vec2,
+    to: Vec2,
+    steering: Vec2,
 ) {
-    let player_transform = match q_player.single() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-    for (enemy_transform, mut slime) in &mut q_slimes {
-        if slime.state == SlimeState::Jumping {
-            continue;
-        }
+    let max_jump_speed = MAX_JUMP_SPEED * slime.size.speed();
+    let ratio = (to.distance(from) / max_jump_speed / JUMP_TIME).min(1.0);
 
-        let distance = player_transform
-            .translation
-            .truncate()
-            .distance(enemy_transform.translation.truncate());
-        let ratio = (distance / MAX_JUMP_SPEED / JUMP_TIME).min(1.0);
-
-        let mut rng = rand::thread_rng();
-        let dir = (player_transform.translation.truncate()
-            - enemy_transform.translation.truncate())
-        .normalize_or_zero();
-        let random_offset =
-            Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)) * RANDOM_OFFSET_INTENSITY;
-
-        slime.jump_speed = ratio * MAX_JUMP_SPEED;
-        slime.jump_direction = dir + random_offset;
-    }
+    let mut rng = rand::thread_rng();
+    let dir = nav_grid.direction(from, to);
+    let random_offset =
+        Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)) * RANDOM_OFFSET_INTENSITY;
+
+    slime.jump_speed = ratio * max_jump_speed;
+    slime.jump_direction = dir + random_offset + steering;
 }
 
-fn move_slimes(mut q_slimes: Query<(&mut Velocity, &SlimeEnemy)>) {
-    for (mut velocity, slime) in &mut q_slimes {
-        if slime.state == SlimeState::Staggering {
+fn move_slimes(
+    mut q_slimes: Query<(
+        &mut Velocity,
+        &SlimeEnemy,
+        &StatusEffects,
+        &SpeedMultiplier,
+        &Steering,
+    )>,
+) {
+    for (mut velocity, slime, status_effects, speed, steering) in &mut q_slimes {
+        if slime.state == SlimeState::Staggering || status_effects.has(StatusKind::KnockedBack) {
             continue;
         }
-        if slime.state != SlimeState::Jumping {
+        if slime.state == SlimeState::Dying {
             velocity.linvel = Vec2::ZERO;
             continue;
         }
-        velocity.linvel = slime.jump_direction * slime.jump_speed;
+        if slime.state != SlimeState::Jumping {
+            // Slowly drift apart so that crowds stay readable.
+            velocity.linvel = **steering * SEPARATION_SPEED;
+            continue;
+        }
+        velocity.linvel = slime.jump_direction * slime.jump_speed * status_effects.slow() * **speed;
     }
 }
 
@@ -54,6 +62,6 @@ pub struct SlimeMovementPlugin;
 
 impl Plugin for SlimeMovementPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (update_jump_position, move_slimes));
+        app.add_systems(Update, move_slimes.after(steer_enemies));
     }
 }
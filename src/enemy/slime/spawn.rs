@@ -1,28 +1,49 @@
+use std::f32::consts::TAU;
+
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use super::{Enemy, SlimeEnemy, SlimeState, SpawnSlimeEnemy};
+use super::collision::stagger_slimes;
+use super::{Enemy, SlimeEnemy, SlimeSize, SlimeState, SPLIT_COUNT};
 use crate::audio::PlaySound;
+use crate::enemy::elite::Elite;
+use crate::enemy::{EnemyKind, EnemyModifiers, HitSound, SpawnEnemy};
+use crate::spell::damage::{DamageType, Hitbox, Hurtbox, Knockback, Resistances};
+use crate::spell::invulnerable::Invulnerable;
+use crate::spell::status::{StatusEffects, StatusKind};
+use crate::spell::Faction;
 use crate::ui::health::Health;
 use crate::utils::anim_sprite::{AnimationIndices, FrameTimer};
 use crate::world::camera::YSort;
 use crate::{GameAssets, GameState};
 
+const HEALTH: f32 = 10.0;
+const DAMAGE: f32 = 1.0;
+const KNOCKBACK: f32 = 200.0;
+/// How far apart the small slimes spawn when splitting.
+const SPLIT_OFFSET: f32 = 12.0;
+const SPLIT_SPEED: f32 = 100.0;
+const SPLIT_INVULNERABILITY: f32 = 0.5;
+
 fn spawn_slime(
     commands: &mut Commands,
     assets: &Res<GameAssets>,
     spawn_pos: Vec3,
+    size: SlimeSize,
+    modifiers: EnemyModifiers,
     ev_play_sound: &mut EventWriter<PlaySound>,
-) {
+) -> Entity {
     let entity = commands
         .spawn((
             RigidBody::Dynamic,
             LockedAxes::ROTATION_LOCKED,
             Velocity::zero(),
             Ccd::enabled(),
-            Enemy { damage: 1.0 },
-            Health::new(10.0),
-            SlimeEnemy::default(),
+            Enemy,
+            Health::new(HEALTH * size.health() * modifiers.health),
+            modifiers.resistances,
+            SlimeEnemy::new(size),
+            HitSound(assets.slime_hit_sound.clone()),
             AnimationIndices { first: 0, last: 5 },
             FrameTimer(Timer::from_seconds(0.085, TimerMode::Repeating)),
             YSort(0.0),
@@ -33,7 +54,7 @@ fn spawn_slime(
                     ..default()
                 },
             ),
-            Transform::from_translation(spawn_pos).with_scale(Vec3::splat(1.5)),
+            Transform::from_translation(spawn_pos).with_scale(Vec3::splat(size.scale())),
         ))
         .id();
 
@@ -41,11 +62,21 @@ fn spawn_slime(
         .spawn((
             Collider::ball(6.0),
             ActiveEvents::COLLISION_EVENTS,
+            Hurtbox::default(),
+            Hitbox {
+                damage: DAMAGE,
+                damage_type: DamageType::Physical,
+                knockback: Knockback::Radial(KNOCKBACK),
+                faction: Faction::Enemy,
+            },
             Transform::from_translation(Vec3::new(0.0, -10.0, 0.0)),
         ))
         .id();
 
     commands.entity(entity).add_children(&[collider]);
+    if let Some(modifier) = modifiers.elite {
+        commands.entity(entity).insert(Elite::new(modifier));
+    }
 
     ev_play_sound.write(PlaySound {
         clip: assets.slime_land_sound.clone(),
@@ -53,26 +84,80 @@ fn spawn_slime(
         parent: Some(entity),
         ..default()
     });
+
+    entity
 }
 
 fn spawn_slimes(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    mut ev_spawn_slime_enemy: EventReader<SpawnSlimeEnemy>,
+    mut ev_spawn_enemy: EventReader<SpawnEnemy>,
     mut ev_play_sound: EventWriter<PlaySound>,
 ) {
-    for ev in ev_spawn_slime_enemy.read() {
-        spawn_slime(&mut commands, &assets, ev.pos, &mut ev_play_sound);
+    for ev in ev_spawn_enemy.read() {
+        let size = match ev.kind {
+            EnemyKind::Slime => SlimeSize::Normal,
+            EnemyKind::LargeSlime => SlimeSize::Large,
+            _ => continue,
+        };
+        spawn_slime(
+            &mut commands,
+            &assets,
+            ev.pos,
+            size,
+            ev.modifiers,
+            &mut ev_play_sound,
+        );
+    }
+}
+
+/// The small slimes fly off in the direction the large slime was knocked back in.
+///
+/// They spawn while the large slime is still dying, so there is always at least one enemy
+/// alive and statues can't get unlocked in between.
+fn split_slime(
+    commands: &mut Commands,
+    assets: &Res<GameAssets>,
+    pos: Vec3,
+    slime: &SlimeEnemy,
+    resistances: Resistances,
+    ev_play_sound: &mut EventWriter<PlaySound>,
+) {
+    let knockback = slime.knockback.normalize_or_zero();
+    for i in 0..SPLIT_COUNT {
+        let rot = Quat::from_rotation_z(TAU * i as f32 / SPLIT_COUNT as f32);
+        let offset = rot.mul_vec3(Vec3::X);
+        let modifiers = EnemyModifiers {
+            resistances,
+            ..default()
+        };
+
+        let entity = spawn_slime(
+            commands,
+            assets,
+            pos + offset * SPLIT_OFFSET,
+            SlimeSize::Small,
+            modifiers,
+            ev_play_sound,
+        );
+
+        let mut status_effects = StatusEffects::default();
+        status_effects.apply(StatusKind::KnockedBack);
+        commands.entity(entity).insert((
+            Velocity::linear((knockback + offset.truncate() * 0.5) * SPLIT_SPEED),
+            status_effects,
+            Invulnerable::new(SPLIT_INVULNERABILITY),
+        ));
     }
 }
 
 fn despawn_slimes(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    mut q_slimes: Query<(Entity, &Health, &mut SlimeEnemy)>,
+    mut q_slimes: Query<(Entity, &Transform, &Health, &Resistances, &mut SlimeEnemy)>,
     mut ev_play_sound: EventWriter<PlaySound>,
 ) {
-    for (entity, health, mut slime) in &mut q_slimes {
+    for (entity, transform, health, resistances, mut slime) in &mut q_slimes {
         if health.health <= 0.0 && slime.state != SlimeState::Dying {
             ev_play_sound.write(PlaySound {
                 clip: assets.slime_death_sound.clone(),
@@ -80,6 +165,17 @@ fn despawn_slimes(
                 ..default()
             });
             slime.state = SlimeState::Dying;
+
+            if slime.size == SlimeSize::Large {
+                split_slime(
+                    &mut commands,
+                    &assets,
+                    transform.translation,
+                    &slime,
+                    *resistances,
+                    &mut ev_play_sound,
+                );
+            }
         }
         if slime.disabled {
             commands.entity(entity).despawn();
@@ -93,7 +189,8 @@ impl Plugin for SlimeSpawnPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (spawn_slimes, despawn_slimes).run_if(in_state(GameState::Gaming)),
+            (spawn_slimes, despawn_slimes.after(stagger_slimes))
+                .run_if(in_state(GameState::Gaming)),
         );
     }
 }
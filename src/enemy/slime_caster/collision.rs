@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use super::{SlimeCaster, SlimeCasterState};
+use crate::spell::damage::{apply_damage, DamageEvent};
+
+/// Getting hit interrupts the cast.
+fn stagger_slime_casters(
+    mut q_casters: Query<&mut SlimeCaster>,
+    mut ev_damage: EventReader<DamageEvent>,
+) {
+    for ev in ev_damage.read() {
+        if let Ok(mut caster) = q_casters.get_mut(ev.target) {
+            if caster.state != SlimeCasterState::Dying {
+                caster.state = SlimeCasterState::Staggering;
+            }
+        }
+    }
+}
+
+pub struct SlimeCasterCollisionPlugin;
+
+impl Plugin for SlimeCasterCollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (stagger_slime_casters.after(apply_damage),));
+    }
+}
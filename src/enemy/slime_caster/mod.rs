@@ -0,0 +1,167 @@
+mod collision;
+mod movement;
+mod slime_ball;
+mod spawn;
+mod sprite;
+
+use bevy::prelude::*;
+
+use super::{Enemy, SpeedMultiplier};
+use crate::audio::PlaySound;
+use crate::player::Player;
+use crate::spell::status::{StatusEffects, StatusKind};
+use crate::{GameAssets, GameState};
+
+/// The caster tries to stay between these distances to the player.
+const MIN_DISTANCE: f32 = 140.0;
+const MAX_DISTANCE: f32 = 220.0;
+/// Only starts casting when the player is this close.
+const CAST_RANGE: f32 = 300.0;
+const MOVE_SPEED: f32 = 60.0;
+
+const CAST_COOLDOWN: f32 = 2.5;
+const TELEGRAPH_TIME: f32 = 0.8;
+const STAGGERING_TIME: f32 = 0.2;
+
+pub struct SlimeCasterPlugin;
+
+impl Plugin for SlimeCasterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            spawn::SlimeCasterSpawnPlugin,
+            movement::SlimeCasterMovementPlugin,
+            sprite::SlimeCasterSpritePlugin,
+            collision::SlimeCasterCollisionPlugin,
+            slime_ball::SlimeBallPlugin,
+        ))
+        .add_event::<CastSlimeBall>()
+        .add_systems(
+            Update,
+            (change_slime_caster_states)
+                .run_if(in_state(GameState::Gaming).or(in_state(GameState::GameOver))),
+        );
+    }
+}
+
+#[derive(Default, PartialEq, Clone, Copy)]
+pub enum SlimeCasterState {
+    #[default]
+    Idling,
+    /// Walking to get back into the preferred distance to the player.
+    Repositioning,
+    /// About to cast, this is the window for the player to react.
+    Telegraphing,
+    Staggering,
+    Dying,
+}
+
+#[derive(Component)]
+struct SlimeCaster {
+    state: SlimeCasterState,
+    cast_cooldown_timer: Timer,
+    telegraph_timer: Timer,
+    death_timer: Timer,
+    staggering_timer: Timer,
+    disabled: bool,
+}
+
+impl Default for SlimeCaster {
+    fn default() -> Self {
+        Self {
+            state: SlimeCasterState::Idling,
+            cast_cooldown_timer: Timer::from_seconds(CAST_COOLDOWN, TimerMode::Repeating),
+            telegraph_timer: Timer::from_seconds(TELEGRAPH_TIME, TimerMode::Repeating),
+            death_timer: Timer::from_seconds(0.070 * 6.0, TimerMode::Once),
+            staggering_timer: Timer::from_seconds(STAGGERING_TIME, TimerMode::Repeating),
+            disabled: false,
+        }
+    }
+}
+
+#[derive(Event)]
+struct CastSlimeBall {
+    caster: Entity,
+}
+
+fn change_slime_caster_states(
+    assets: Res<GameAssets>,
+    time: Res<Time>,
+    q_player: Query<&Transform, With<Player>>,
+    mut q_casters: Query<
+        (
+            Entity,
+            &Transform,
+            &mut SlimeCaster,
+            &StatusEffects,
+            &SpeedMultiplier,
+        ),
+        With<Enemy>,
+    >,
+    mut ev_cast_slime_ball: EventWriter<CastSlimeBall>,
+    mut ev_play_sound: EventWriter<PlaySound>,
+) {
+    let player_pos = match q_player.single() {
+        Ok(p) => p.translation.truncate(),
+        Err(_) => return,
+    };
+
+    for (entity, transform, mut caster, status_effects, speed) in &mut q_casters {
+        if caster.state != SlimeCasterState::Dying && status_effects.has(StatusKind::Shocked) {
+            continue;
+        }
+
+        let distance = player_pos.distance(transform.translation.truncate());
+        match caster.state {
+            SlimeCasterState::Idling | SlimeCasterState::Repositioning => {
+                caster.state = if (MIN_DISTANCE..=MAX_DISTANCE).contains(&distance) {
+                    SlimeCasterState::Idling
+                } else {
+                    SlimeCasterState::Repositioning
+                };
+
+                // Frozen casters take longer to get ready for the next cast.
+                caster
+                    .cast_cooldown_timer
+                    .tick(time.delta().mul_f32(status_effects.slow() * **speed));
+                if caster.cast_cooldown_timer.just_finished() && distance <= CAST_RANGE {
+                    ev_play_sound.write(PlaySound {
+                        clip: assets.slime_jump_sound.clone(),
+                        volume: 0.75,
+                        playback_rate: 0.6,
+                        rand_speed_intensity: 0.1,
+                        parent: Some(entity),
+                        ..default()
+                    });
+                    caster.telegraph_timer.reset();
+                    caster.state = SlimeCasterState::Telegraphing;
+                }
+            }
+            SlimeCasterState::Telegraphing => {
+                caster.telegraph_timer.tick(time.delta());
+                if caster.telegraph_timer.just_finished() {
+                    ev_play_sound.write(PlaySound {
+                        clip: assets.slime_land_sound.clone(),
+                        playback_rate: 1.5,
+                        rand_speed_intensity: 0.2,
+                        parent: Some(entity),
+                        ..default()
+                    });
+                    ev_cast_slime_ball.write(CastSlimeBall { caster: entity });
+                    caster.state = SlimeCasterState::Idling;
+                }
+            }
+            SlimeCasterState::Staggering => {
+                caster.staggering_timer.tick(time.delta());
+                if caster.staggering_timer.just_finished() {
+                    caster.state = SlimeCasterState::Idling;
+                }
+            }
+            SlimeCasterState::Dying => {
+                caster.death_timer.tick(time.delta());
+                if caster.death_timer.just_finished() {
+                    caster.disabled = true;
+                }
+            }
+        };
+    }
+}
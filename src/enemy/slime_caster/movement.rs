@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::{SlimeCaster, SlimeCasterState, MAX_DISTANCE, MIN_DISTANCE, MOVE_SPEED};
+use crate::enemy::steering::{steer_enemies, Steering};
+use crate::enemy::SpeedMultiplier;
+use crate::player::Player;
+use crate::spell::status::{StatusEffects, StatusKind};
+
+fn move_slime_casters(
+    q_player: Query<&Transform, With<Player>>,
+    mut q_casters: Query<
+        (
+            &Transform,
+            &mut Velocity,
+            &SlimeCaster,
+            &StatusEffects,
+            &SpeedMultiplier,
+            &Steering,
+        ),
+        Without<Player>,
+    >,
+) {
+    let player_pos = match q_player.single() {
+        Ok(p) => p.translation.truncate(),
+        Err(_) => return,
+    };
+
+    for (transform, mut velocity, caster, status_effects, speed, steering) in &mut q_casters {
+        if caster.state == SlimeCasterState::Staggering
+            || status_effects.has(StatusKind::KnockedBack)
+        {
+            continue;
+        }
+        if caster.state == SlimeCasterState::Dying {
+            velocity.linvel = Vec2::ZERO;
+            continue;
+        }
+        if caster.state != SlimeCasterState::Repositioning {
+            // Make room for other casters, but stay put otherwise.
+            velocity.linvel = **steering * MOVE_SPEED * 0.5;
+            continue;
+        }
+
+        let diff = player_pos - transform.translation.truncate();
+        let dir = if diff.length() < MIN_DISTANCE {
+            -diff.normalize_or_zero()
+        } else if diff.length() > MAX_DISTANCE {
+            diff.normalize_or_zero()
+        } else {
+            Vec2::ZERO
+        };
+        velocity.linvel = (dir + **steering) * MOVE_SPEED * status_effects.slow() * **speed;
+    }
+}
+
+pub struct SlimeCasterMovementPlugin;
+
+impl Plugin for SlimeCasterMovementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (move_slime_casters.after(steer_enemies),));
+    }
+}
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::sprite::CASTER_COLOR;
+use super::{CastSlimeBall, SlimeCaster};
+use crate::audio::PlaySound;
+use crate::player::Player;
+use crate::spell::damage::{resolve_hits, DamageType, Hitbox, Instigator, Knockback};
+use crate::spell::projectile::{Projectile, ProjectileHit};
+use crate::spell::Faction;
+use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
+use crate::utils::quat_from_vec2;
+use crate::world::camera::YSort;
+use crate::{GameAssets, GameState};
+
+const SPEED: f32 = 90.0;
+const DAMAGE: f32 = 1.0;
+const KNOCKBACK: f32 = 100.0;
+const SCALE: f32 = 1.0;
+const LIFETIME: f32 = 4.0;
+
+/// Slow projectile of the slime caster, can be blocked with icicles.
+#[derive(Component)]
+struct SlimeBall;
+
+fn spawn_slime_balls(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    q_player: Query<&Transform, With<Player>>,
+    q_casters: Query<&Transform, (With<SlimeCaster>, Without<Player>)>,
+    mut ev_cast_slime_ball: EventReader<CastSlimeBall>,
+) {
+    let player_pos = match q_player.single() {
+        Ok(p) => p.translation,
+        Err(_) => return,
+    };
+
+    for ev in ev_cast_slime_ball.read() {
+        let caster_pos = match q_casters.get(ev.caster) {
+            Ok(c) => c.translation,
+            Err(_) => continue,
+        };
+
+        let mut sprite = Sprite::from_atlas_image(
+            assets.fireball_texture.clone(),
+            TextureAtlas {
+                layout: assets.fireball_layout.clone(),
+                ..default()
+            },
+        );
+        sprite.flip_x = true;
+        sprite.color = CASTER_COLOR;
+
+        let dir = (player_pos - caster_pos).truncate().normalize_or_zero();
+        let entity = commands
+            .spawn((
+                SlimeBall,
+                Instigator(ev.caster),
+                Projectile {
+                    speed: SPEED,
+                    lifetime: Timer::from_seconds(LIFETIME, TimerMode::Once),
+                    scale_in: Some(SCALE),
+                    blockable: true,
+                    ..default()
+                },
+                YSort(10.0),
+                AnimSprite::new(60, true),
+                AnimSpriteTimer::new(0.05),
+                sprite,
+                Transform::from_translation(caster_pos)
+                    .with_scale(Vec3::ZERO)
+                    .with_rotation(quat_from_vec2(dir)),
+            ))
+            .id();
+
+        let collider = commands
+            .spawn((
+                Collider::ball(5.0),
+                Sensor,
+                // Icicles have no rigid body either, see `block_projectiles`.
+                ActiveEvents::COLLISION_EVENTS,
+                ActiveCollisionTypes::default() | ActiveCollisionTypes::STATIC_STATIC,
+                Hitbox {
+                    damage: DAMAGE,
+                    damage_type: DamageType::Physical,
+                    knockback: Knockback::Radial(KNOCKBACK),
+                    faction: Faction::Enemy,
+                },
+                Transform::from_translation(Vec3::new(25.0, 0.0, 0.0)),
+            ))
+            .id();
+
+        commands.entity(entity).add_children(&[collider]);
+    }
+}
+
+fn splash_slime_balls(
+    assets: Res<GameAssets>,
+    q_slime_balls: Query<(), With<SlimeBall>>,
+    mut ev_projectile_hit: EventReader<ProjectileHit>,
+    mut ev_play_sound: EventWriter<PlaySound>,
+) {
+    for ev in ev_projectile_hit.read() {
+        if !q_slime_balls.contains(ev.projectile) {
+            continue;
+        }
+
+        ev_play_sound.write(PlaySound {
+            clip: assets.slime_land_sound.clone(),
+            playback_rate: 1.5,
+            rand_speed_intensity: 0.2,
+            parent: Some(ev.target),
+            ..default()
+        });
+    }
+}
+
+pub struct SlimeBallPlugin;
+
+impl Plugin for SlimeBallPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_slime_balls, splash_slime_balls.after(resolve_hits))
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
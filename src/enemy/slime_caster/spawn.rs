@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::sprite::CASTER_COLOR;
+use super::{Enemy, SlimeCaster, SlimeCasterState};
+use crate::audio::PlaySound;
+use crate::enemy::elite::Elite;
+use crate::enemy::{EnemyKind, EnemyModifiers, HitSound, SpawnEnemy};
+use crate::spell::damage::{DamageType, Hitbox, Hurtbox, Knockback};
+use crate::spell::Faction;
+use crate::ui::health::Health;
+use crate::utils::anim_sprite::{AnimationIndices, FrameTimer};
+use crate::world::camera::YSort;
+use crate::{GameAssets, GameState};
+
+const HEALTH: f32 = 6.0;
+const DAMAGE: f32 = 1.0;
+const KNOCKBACK: f32 = 150.0;
+
+fn spawn_slime_caster(
+    commands: &mut Commands,
+    assets: &Res<GameAssets>,
+    spawn_pos: Vec3,
+    modifiers: EnemyModifiers,
+    ev_play_sound: &mut EventWriter<PlaySound>,
+) {
+    let mut sprite = Sprite::from_atlas_image(
+        assets.slime_texture.clone(),
+        TextureAtlas {
+            layout: assets.slime_layout.clone(),
+            ..default()
+        },
+    );
+    sprite.color = CASTER_COLOR;
+
+    let entity = commands
+        .spawn((
+            RigidBody::Dynamic,
+            LockedAxes::ROTATION_LOCKED,
+            Velocity::zero(),
+            Ccd::enabled(),
+            Enemy,
+            Health::new(HEALTH * modifiers.health),
+            modifiers.resistances,
+            SlimeCaster::default(),
+            HitSound(assets.slime_hit_sound.clone()),
+            AnimationIndices { first: 0, last: 5 },
+            FrameTimer(Timer::from_seconds(0.085, TimerMode::Repeating)),
+            YSort(0.0),
+            sprite,
+            Transform::from_translation(spawn_pos).with_scale(Vec3::splat(1.25)),
+        ))
+        .id();
+
+    let collider = commands
+        .spawn((
+            Collider::ball(6.0),
+            ActiveEvents::COLLISION_EVENTS,
+            Hurtbox::default(),
+            Hitbox {
+                damage: DAMAGE,
+                damage_type: DamageType::Physical,
+                knockback: Knockback::Radial(KNOCKBACK),
+                faction: Faction::Enemy,
+            },
+            Transform::from_translation(Vec3::new(0.0, -10.0, 0.0)),
+        ))
+        .id();
+
+    commands.entity(entity).add_children(&[collider]);
+    if let Some(modifier) = modifiers.elite {
+        commands.entity(entity).insert(Elite::new(modifier));
+    }
+
+    ev_play_sound.write(PlaySound {
+        clip: assets.slime_land_sound.clone(),
+        rand_speed_intensity: 0.2,
+        parent: Some(entity),
+        ..default()
+    });
+}
+
+fn spawn_slime_casters(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut ev_spawn_enemy: EventReader<SpawnEnemy>,
+    mut ev_play_sound: EventWriter<PlaySound>,
+) {
+    for ev in ev_spawn_enemy.read() {
+        if ev.kind == EnemyKind::SlimeCaster {
+            spawn_slime_caster(
+                &mut commands,
+                &assets,
+                ev.pos,
+                ev.modifiers,
+                &mut ev_play_sound,
+            );
+        }
+    }
+}
+
+fn despawn_slime_casters(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut q_casters: Query<(Entity, &Health, &mut SlimeCaster)>,
+    mut ev_play_sound: EventWriter<PlaySound>,
+) {
+    for (entity, health, mut caster) in &mut q_casters {
+        if health.health <= 0.0 && caster.state != SlimeCasterState::Dying {
+            ev_play_sound.write(PlaySound {
+                clip: assets.slime_death_sound.clone(),
+                parent: Some(entity),
+                ..default()
+            });
+            caster.state = SlimeCasterState::Dying;
+        }
+        if caster.disabled {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub struct SlimeCasterSpawnPlugin;
+
+impl Plugin for SlimeCasterSpawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_slime_casters, despawn_slime_casters).run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use super::{SlimeCaster, SlimeCasterState};
+use crate::spell::status::{tint_sprites, StatusEffects};
+use crate::utils::anim_sprite::AnimationIndices;
+
+/// Tells casters apart from regular slimes.
+pub const CASTER_COLOR: Color = Color::srgb(0.8, 0.55, 1.0);
+const TELEGRAPH_COLOR: Color = Color::srgb(1.0, 0.35, 0.6);
+const TELEGRAPH_BLINKS: f32 = 4.0;
+
+fn slime_caster_sprite_indices(state: &SlimeCasterState) -> (usize, usize) {
+    match state {
+        SlimeCasterState::Idling => (0, 5),
+        SlimeCasterState::Repositioning => (6, 11),
+        SlimeCasterState::Telegraphing => (0, 5),
+        SlimeCasterState::Staggering => (18, 18),
+        SlimeCasterState::Dying => (12, 17),
+    }
+}
+
+fn update_indicies(mut q_casters: Query<(&mut AnimationIndices, &mut Sprite, &SlimeCaster)>) {
+    for (mut indices, mut image, caster) in &mut q_casters {
+        let new_indices = slime_caster_sprite_indices(&caster.state);
+
+        if new_indices.0 != indices.first {
+            indices.first = new_indices.0;
+            indices.last = new_indices.1;
+            if let Some(layout) = image.texture_atlas.as_mut() {
+                layout.index = indices.first
+            }
+        }
+    }
+}
+
+/// Blink while telegraphing, status effects take priority.
+fn update_colors(mut q_casters: Query<(&mut Sprite, &SlimeCaster, &StatusEffects)>) {
+    for (mut sprite, caster, status_effects) in &mut q_casters {
+        if status_effects.tint().is_some() {
+            continue;
+        }
+
+        let blink = caster.state == SlimeCasterState::Telegraphing
+            && (caster.telegraph_timer.fraction() * TELEGRAPH_BLINKS).fract() < 0.5;
+        let color = if blink { TELEGRAPH_COLOR } else { CASTER_COLOR };
+        let alpha = sprite.color.alpha();
+        sprite.color = color.with_alpha(alpha);
+    }
+}
+
+pub struct SlimeCasterSpritePlugin;
+
+impl Plugin for SlimeCasterSpritePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (update_indicies, update_colors.after(tint_sprites)));
+    }
+}
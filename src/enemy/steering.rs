@@ -0,0 +1,98 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use super::Enemy;
+use crate::GameState;
+
+/// Enemies closer than this push each other away.
+const SEPARATION_RADIUS: f32 = 40.0;
+const SEPARATION_WEIGHT: f32 = 1.5;
+/// Enemies pull slightly towards the center of the enemies in this radius.
+const COHESION_RADIUS: f32 = 96.0;
+const COHESION_WEIGHT: f32 = 0.15;
+const MAX_STEERING: f32 = 1.0;
+/// The size of the cells of the spatial hash, neighbours are only looked up in adjacent cells.
+const CELL_SIZE: f32 = COHESION_RADIUS;
+/// Used to spread enemies that spawned on the exact same position.
+const GOLDEN_ANGLE: f32 = 2.399_963;
+
+/// Added on top of the direction an enemy wants to move in, keeps crowds from overlapping.
+#[derive(Component, Default, Deref)]
+pub struct Steering(Vec2);
+
+fn cell_from_pos(pos: Vec2) -> IVec2 {
+    (pos / CELL_SIZE).floor().as_ivec2()
+}
+
+pub fn steer_enemies(
+    mut spatial_hash: Local<HashMap<IVec2, Vec<(Entity, Vec2)>>>,
+    mut q_enemies: Query<(Entity, &Transform, &mut Steering), With<Enemy>>,
+) {
+    for positions in spatial_hash.values_mut() {
+        positions.clear();
+    }
+    for (entity, transform, _) in &q_enemies {
+        let pos = transform.translation.truncate();
+        spatial_hash
+            .entry(cell_from_pos(pos))
+            .or_default()
+            .push((entity, pos));
+    }
+    // Keep the cells that are still in use to reuse their allocations, drop the rest.
+    spatial_hash.retain(|_, positions| !positions.is_empty());
+
+    for (entity, transform, mut steering) in &mut q_enemies {
+        let pos = transform.translation.truncate();
+        let cell = cell_from_pos(pos);
+
+        let mut separation = Vec2::ZERO;
+        let mut center = Vec2::ZERO;
+        let mut neighbours = 0;
+        for y in -1..=1 {
+            for x in -1..=1 {
+                let positions = match spatial_hash.get(&(cell + IVec2::new(x, y))) {
+                    Some(p) => p,
+                    None => continue,
+                };
+
+                for (other_entity, other) in positions {
+                    if *other_entity == entity {
+                        continue;
+                    }
+
+                    let offset = pos - *other;
+                    let distance = offset.length();
+                    if distance == 0.0 {
+                        separation += Vec2::from_angle(entity.index() as f32 * GOLDEN_ANGLE);
+                        continue;
+                    }
+                    if distance > COHESION_RADIUS {
+                        continue;
+                    }
+
+                    center += *other;
+                    neighbours += 1;
+                    if distance < SEPARATION_RADIUS {
+                        // Closer enemies push harder.
+                        separation += offset / distance * (1.0 - distance / SEPARATION_RADIUS);
+                    }
+                }
+            }
+        }
+
+        let cohesion = if neighbours > 0 {
+            (center / neighbours as f32 - pos).normalize_or_zero()
+        } else {
+            Vec2::ZERO
+        };
+        steering.0 = (separation * SEPARATION_WEIGHT + cohesion * COHESION_WEIGHT)
+            .clamp_length_max(MAX_STEERING);
+    }
+}
+
+pub struct SteeringPlugin;
+
+impl Plugin for SteeringPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, steer_enemies.run_if(in_state(GameState::Gaming)));
+    }
+}
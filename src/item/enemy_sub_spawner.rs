@@ -5,7 +5,10 @@ use rand::{rngs::ThreadRng, thread_rng, Rng};
 use bevy::prelude::*;
 
 use super::statue::Statue;
-use crate::{enemy::slime::SpawnSlimeEnemy, GameState};
+use crate::{
+    enemy::{elite::EliteRoll, EnemyKind, EnemyModifiers, SpawnEnemy},
+    GameState,
+};
 
 #[derive(Clone)]
 pub enum SpawnFormation {
@@ -22,6 +25,11 @@ pub struct EnemySubSpawner {
     pub radius: f32,
     pub angle: f32,
     pub spawn_formation: SpawnFormation,
+    pub kind: EnemyKind,
+    /// E.g. resistances that force the player to switch spells.
+    pub modifiers: EnemyModifiers,
+    /// Turns some of the spawned enemies into elites.
+    pub elites: EliteRoll,
     pub timer: Timer,
     pub disabled: bool,
 }
@@ -35,6 +43,9 @@ impl Default for EnemySubSpawner {
             radius: 150.0,
             angle: 0.0,
             spawn_formation: SpawnFormation::Circle,
+            kind: EnemyKind::default(),
+            modifiers: EnemyModifiers::default(),
+            elites: EliteRoll::default(),
             timer: Timer::from_seconds(0.0, TimerMode::Once),
             disabled: false,
         }
@@ -68,7 +79,7 @@ fn despawn_sub_spawners(
 
 fn spawn_enemy(
     sub_spawner: &mut EnemySubSpawner,
-    ev_spawn_slime_enemy: &mut EventWriter<SpawnSlimeEnemy>,
+    ev_spawn_enemy: &mut EventWriter<SpawnEnemy>,
     rng: &mut ThreadRng,
 ) {
     let pos = match sub_spawner.spawn_formation {
@@ -91,13 +102,22 @@ fn spawn_enemy(
         }
     };
 
+    let mut modifiers = sub_spawner.modifiers;
+    if modifiers.elite.is_none() {
+        modifiers.elite = sub_spawner.elites.roll(rng);
+    }
+
     sub_spawner.current_index += 1;
-    ev_spawn_slime_enemy.write(SpawnSlimeEnemy { pos });
+    ev_spawn_enemy.write(SpawnEnemy {
+        kind: sub_spawner.kind,
+        pos,
+        modifiers,
+    });
 }
 
 fn spawn_enemies(
     mut q_sub_spawners: Query<&mut EnemySubSpawner>,
-    mut ev_spawn_slime_enemy: EventWriter<SpawnSlimeEnemy>,
+    mut ev_spawn_enemy: EventWriter<SpawnEnemy>,
 ) {
     let mut rng = thread_rng();
 
@@ -109,14 +129,14 @@ fn spawn_enemies(
         // Spawn all enemies at once.
         if sub_spawner.timer.duration().as_secs_f32() == 0.0 {
             for _ in 0..sub_spawner.count {
-                spawn_enemy(&mut sub_spawner, &mut ev_spawn_slime_enemy, &mut rng);
+                spawn_enemy(&mut sub_spawner, &mut ev_spawn_enemy, &mut rng);
             }
             continue;
         }
 
         // Spawn single enemy based on timer.
         if sub_spawner.timer.just_finished() {
-            spawn_enemy(&mut sub_spawner, &mut ev_spawn_slime_enemy, &mut rng);
+            spawn_enemy(&mut sub_spawner, &mut ev_spawn_enemy, &mut rng);
         }
     }
 }
@@ -2,7 +2,14 @@ use std::f32::consts::PI;
 
 use bevy::prelude::*;
 
-use crate::{spell::Spell, GameAssets};
+use crate::{
+    enemy::{
+        elite::{EliteModifier, EliteRoll},
+        EnemyKind, EnemyModifiers,
+    },
+    spell::{damage::Resistances, registry::SpellRegistry},
+    GameAssets,
+};
 
 use super::{
     enemy_sub_spawner::{EnemySubSpawner, SpawnFormation},
@@ -10,51 +17,27 @@ use super::{
     Item,
 };
 
-pub fn item_title(item: &Item) -> String {
-    match item {
-        Item::NotImplemented => "NOT IMPLEMENTED, you should not see this, please report",
-        Item::Tutorial => "Spell Console",
-        Item::IgnisPila => "Ignis Pila",
-        Item::InfernoPila => "Inferno Pila",
-        Item::Fulgur => "Fulgur",
-        Item::ScutumGlaciei => "Scutum Glaciei",
-        Item::AerTracto => "Aer Tracto",
-        Item::AerPello => "Aer Pello",
-        Item::FulgurAvis => "Fulgur Avis",
+pub fn item_title(registry: &SpellRegistry, item: &Item) -> String {
+    match registry.item_entry(item) {
+        Some(entry) => entry.title.clone(),
+        None => "NOT IMPLEMENTED, you should not see this, please report".to_string(),
     }
-    .to_string()
 }
 
-pub fn item_description(item: &Item) -> String {
-    match item {
-        Item::NotImplemented => "CONTENT DESCRIPTION",
-        Item::Tutorial => {
-            "Press 'i' to open your spell console.\nThen type your spell, try 'fireball'."
-        }
-        Item::IgnisPila => "Cast 5 fireballs.",
-        Item::InfernoPila => "Cast MANY fireballs omni directionally",
-        Item::Fulgur => {
-            "Call down lightning strikes on random enemies.\nOnly works when there are enemies."
-        }
-        Item::ScutumGlaciei => "Materialize 10 ice crystals that cycle around you for 10 seconds.",
-        Item::AerTracto => "Pull enemies towards you.",
-        Item::AerPello => "Push enemies away from you.",
-        Item::FulgurAvis => "Summon a powerful lightning bird.",
+pub fn item_description(registry: &SpellRegistry, item: &Item) -> String {
+    match registry.item_entry(item) {
+        Some(entry) => entry.description.clone(),
+        None => "CONTENT DESCRIPTION".to_string(),
     }
-    .to_string()
 }
 
-pub fn item_icon(assets: &Res<GameAssets>, item: &Item) -> Handle<Image> {
-    match item {
-        Item::NotImplemented => assets.placeholder_icon.clone(),
-        Item::Tutorial => assets.spell_console_icon.clone(),
-        Item::IgnisPila => assets.ignis_pila_icon.clone(),
-        Item::InfernoPila => assets.inferno_pila_icon.clone(),
-        Item::Fulgur => assets.fulgur_icon.clone(),
-        Item::ScutumGlaciei => assets.scutum_glaciei_icon.clone(),
-        Item::AerTracto => assets.aer_tracto_icon.clone(),
-        Item::AerPello => assets.aer_pello_icon.clone(),
-        Item::FulgurAvis => assets.fulgur_avis_icon.clone(),
+pub fn item_icon(assets: &Res<GameAssets>, registry: &SpellRegistry, item: &Item) -> Handle<Image> {
+    match registry
+        .item_entry(item)
+        .and_then(|entry| entry.icon.clone())
+    {
+        Some(icon) => icon,
+        None => assets.placeholder_icon.clone(),
     }
 }
 
@@ -72,20 +55,6 @@ pub fn item_wall_offset(item: &Item) -> f32 {
     }
 }
 
-pub fn item_spell(item: &Item) -> Spell {
-    match item {
-        Item::NotImplemented => Spell::Flub,
-        Item::Tutorial => Spell::Fireball,
-        Item::IgnisPila => Spell::IgnisPila,
-        Item::InfernoPila => Spell::InfernoPila,
-        Item::Fulgur => Spell::Fulgur,
-        Item::ScutumGlaciei => Spell::ScutumGlaciei,
-        Item::AerTracto => Spell::AerTracto,
-        Item::AerPello => Spell::AerPello,
-        Item::FulgurAvis => Spell::FulgurAvis,
-    }
-}
-
 pub fn statue_sub_spawner(statue: &Statue) -> Vec<(f32, EnemySubSpawner)> {
     match statue.item {
         Item::NotImplemented => Vec::new(),
@@ -135,17 +104,31 @@ pub fn statue_sub_spawner(statue: &Statue) -> Vec<(f32, EnemySubSpawner)> {
                 },
             ),
         ],
-        Item::InfernoPila => vec![(
-            0.0,
-            EnemySubSpawner {
-                statue: statue.clone(),
-                count: 6,
-                radius: 160.0,
-                timer: Timer::from_seconds(0.25, TimerMode::Repeating),
-                spawn_formation: SpawnFormation::Circle,
-                ..default()
-            },
-        )],
+        Item::InfernoPila => vec![
+            (
+                4.0,
+                EnemySubSpawner {
+                    statue: statue.clone(),
+                    count: 6,
+                    radius: 160.0,
+                    timer: Timer::from_seconds(0.25, TimerMode::Repeating),
+                    spawn_formation: SpawnFormation::Circle,
+                    ..default()
+                },
+            ),
+            (
+                0.0,
+                EnemySubSpawner {
+                    statue: statue.clone(),
+                    count: 2,
+                    radius: 200.0,
+                    spawn_formation: SpawnFormation::Random,
+                    kind: EnemyKind::LargeSlime,
+                    timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+                    ..default()
+                },
+            ),
+        ],
         Item::ScutumGlaciei => vec![
             (
                 5.0,
@@ -165,10 +148,29 @@ pub fn statue_sub_spawner(statue: &Statue) -> Vec<(f32, EnemySubSpawner)> {
                     count: 8,
                     radius: 180.0,
                     spawn_formation: SpawnFormation::Random,
+                    modifiers: EnemyModifiers {
+                        resistances: Resistances {
+                            fire: 0.5,
+                            ..default()
+                        },
+                        ..default()
+                    },
                     timer: Timer::from_seconds(0.3, TimerMode::Repeating),
                     ..default()
                 },
             ),
+            (
+                0.0,
+                EnemySubSpawner {
+                    statue: statue.clone(),
+                    count: 3,
+                    radius: 200.0,
+                    spawn_formation: SpawnFormation::Circle,
+                    kind: EnemyKind::SlimeCaster,
+                    timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+                    ..default()
+                },
+            ),
         ],
         Item::AerPello => vec![
             (
@@ -206,6 +208,18 @@ pub fn statue_sub_spawner(statue: &Statue) -> Vec<(f32, EnemySubSpawner)> {
                     ..default()
                 },
             ),
+            (
+                0.0,
+                EnemySubSpawner {
+                    statue: statue.clone(),
+                    count: 4,
+                    radius: 270.0,
+                    spawn_formation: SpawnFormation::Circle,
+                    kind: EnemyKind::SlimeCaster,
+                    timer: Timer::from_seconds(0.25, TimerMode::Repeating),
+                    ..default()
+                },
+            ),
         ],
         Item::FulgurAvis => vec![
             (
@@ -226,6 +240,14 @@ pub fn statue_sub_spawner(statue: &Statue) -> Vec<(f32, EnemySubSpawner)> {
                     count: 15,
                     spawn_formation: SpawnFormation::Random,
                     radius: 220.0,
+                    elites: EliteRoll {
+                        chance: 0.2,
+                        modifiers: vec![
+                            EliteModifier::Shielded,
+                            EliteModifier::Hasted,
+                            EliteModifier::Vampiric,
+                        ],
+                    },
                     timer: Timer::from_seconds(0.2, TimerMode::Repeating),
                     ..default()
                 },
@@ -237,6 +259,16 @@ pub fn statue_sub_spawner(statue: &Statue) -> Vec<(f32, EnemySubSpawner)> {
                     count: 50,
                     spawn_formation: SpawnFormation::Circle,
                     radius: 240.0,
+                    elites: EliteRoll {
+                        chance: 0.1,
+                        modifiers: vec![
+                            EliteModifier::Shielded,
+                            EliteModifier::Hasted,
+                            EliteModifier::Regenerating,
+                            EliteModifier::Explosive,
+                            EliteModifier::Vampiric,
+                        ],
+                    },
                     timer: Timer::from_seconds(0.1, TimerMode::Repeating),
                     ..default()
                 },
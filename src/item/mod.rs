@@ -7,6 +7,8 @@ mod enemy_sub_spawner;
 mod sound;
 mod statue_wall;
 
+use serde::Deserialize;
+
 use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 
@@ -30,7 +32,7 @@ impl Plugin for ItemPlugin {
     }
 }
 
-#[derive(Debug, Default, Component, Reflect, Clone, PartialEq)]
+#[derive(Debug, Default, Component, Reflect, Clone, PartialEq, Deserialize)]
 pub enum Item {
     #[default]
     NotImplemented,
@@ -3,6 +3,7 @@ use bevy_ecs_ldtk::prelude::*;
 use bevy_rapier2d::prelude::*;
 
 use crate::enemy::Enemy;
+use crate::player::stats::{Stat, Stats};
 use crate::player::Player;
 use crate::ui::world_text::{SpawnWorldText, WorldText};
 use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
@@ -15,7 +16,6 @@ use super::{ActiveItems, Item};
 
 const BLINK_OFFSET: Vec3 = Vec3::new(0.0, 32.0, 0.0);
 const BEAM_OFFSET: Vec3 = Vec3::new(1.0, 45.0, -10.0);
-const TRIGGER_DISTANCE_SQRT: f32 = 64.0 * 64.0;
 
 #[derive(Component, Clone, Default)]
 pub struct Statue {
@@ -195,12 +195,12 @@ fn tick_unlock_timers(time: Res<Time>, mut q_unlock_timers: Query<&mut UnlockTim
 }
 
 fn trigger_statues(
-    q_player: Query<&Transform, (With<Player>, Without<Statue>)>,
+    q_player: Query<(&Transform, &Stats), (With<Player>, Without<Statue>)>,
     mut q_statues: Query<(&GlobalTransform, &mut Statue)>,
     mut ev_statue_triggered: EventWriter<StatueTriggered>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation,
+    let (player_pos, pickup_radius) = match q_player.single() {
+        Ok((transform, stats)) => (transform.translation, stats.get(Stat::PickupRadius)),
         Err(_) => return,
     };
 
@@ -210,7 +210,7 @@ fn trigger_statues(
         }
 
         let dis = player_pos.distance_squared(statue_transform.translation());
-        if dis > TRIGGER_DISTANCE_SQRT {
+        if dis > pickup_radius * pickup_radius {
             continue;
         }
 
@@ -4,7 +4,8 @@ use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use bevy_trickfilm::animation::AnimationPlayer2D;
 
-use crate::{audio::PlaySound, world::camera::YSort, GameAssets, GameState};
+use crate::world::{camera::YSort, navigation::NavObstacle};
+use crate::{audio::PlaySound, GameAssets, GameState};
 
 use super::{
     item_value::item_wall_offset,
@@ -56,6 +57,7 @@ fn spawn_collider(commands: &mut Commands, pos: Vec3, offset: f32) {
 
     commands.spawn((
         EarthWallCollider,
+        NavObstacle,
         Collider::polyline(vertices, None),
         CollisionGroups::default(),
         Transform::from_translation(pos),
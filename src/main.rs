@@ -63,7 +63,9 @@ fn main() {
         .add_loading_state(
             LoadingState::new(GameState::AssetLoading)
                 .continue_to_state(GameState::Gaming)
-                .load_collection::<GameAssets>(),
+                .load_collection::<GameAssets>()
+                .finally_init_resource::<spell::registry::SpellRegistry>()
+                .finally_init_resource::<enemy::demon_boss::phase::DemonBossPhases>(),
         )
         .add_plugins((
             ui::UiPlugin,
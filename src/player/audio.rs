@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{audio::PlaySound, GameAssets, GameState};
+use crate::{audio::PlaySound, spell::mana::SpellRejected, GameAssets, GameState};
 
 use super::{Player, PlayerState};
 
@@ -56,13 +56,34 @@ fn play_step_sounds(
     });
 }
 
+/// The unlock chime played backwards, so that it sounds like the spell fizzled.
+fn play_reject_sounds(
+    assets: Res<GameAssets>,
+    mut ev_spell_rejected: EventReader<SpellRejected>,
+    mut ev_play_sound: EventWriter<PlaySound>,
+) {
+    if ev_spell_rejected.is_empty() {
+        return;
+    }
+    ev_spell_rejected.clear();
+
+    ev_play_sound.write(PlaySound {
+        clip: assets.item_unlock_sound.clone(),
+        volume: 0.5,
+        playback_rate: 1.5,
+        reverse: true,
+        ..default()
+    });
+}
+
 pub struct PlayerAudioPlugin;
 
 impl Plugin for PlayerAudioPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (play_step_sounds, tick_steps_timers).run_if(in_state(GameState::Gaming)),
+            (play_step_sounds, play_reject_sounds, tick_steps_timers)
+                .run_if(in_state(GameState::Gaming)),
         );
     }
 }
@@ -1,165 +1,22 @@
 use bevy::prelude::*;
-use bevy_rapier2d::prelude::*;
 
-use crate::enemy::demon_boss::explosion::{DemonBossExplosion, DemonBossStrikeExplosion};
-use crate::enemy::demon_boss::DemonBoss;
-use crate::ui::health::Health;
-use crate::{enemy::Enemy, GameState};
+use crate::spell::damage::{apply_damage, DamageEvent};
+use crate::GameState;
 
-use super::{Player, PlayerState, STAGGERING_INTENSITY};
+use super::{Player, PlayerState};
 
-fn slime_collisions(
-    mut q_player: Query<(&mut Velocity, &mut Player, &mut Health, &Transform)>,
-    q_enemies: Query<(&Transform, &Enemy), Without<Player>>,
-    q_colliders: Query<&ChildOf, (With<Collider>, Without<Enemy>, Without<Player>)>,
-    mut ev_collision_events: EventReader<CollisionEvent>,
-) {
-    let (mut velocity, mut player, mut health, player_transform) = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
-    for ev in ev_collision_events.read() {
-        let (source, target) = match ev {
-            CollisionEvent::Started(source, target, _) => (source, target),
-            CollisionEvent::Stopped(_, _, _) => continue,
-        };
-
-        let enemy_parent = if &player.collider_entity == source {
-            match q_colliders.get(*target) {
-                Ok(c) => c.parent(),
-                Err(_) => continue,
-            }
-        } else if &player.collider_entity == target {
-            match q_colliders.get(*source) {
-                Ok(c) => c.parent(),
-                Err(_) => continue,
-            }
-        } else {
-            continue;
-        };
-
-        let (enemy_transform, enemy) = match q_enemies.get(enemy_parent) {
-            Ok(e) => (e.0, e.1),
+fn stagger_player(mut q_player: Query<&mut Player>, mut ev_damage: EventReader<DamageEvent>) {
+    for ev in ev_damage.read() {
+        let mut player = match q_player.get_mut(ev.target) {
+            Ok(p) => p,
             Err(_) => continue,
         };
 
-        if player.state == PlayerState::Staggering {
-            continue;
-        }
-
-        health.health -= enemy.damage;
         player.state = PlayerState::Staggering;
-
-        let dir = (player_transform.translation - enemy_transform.translation)
-            .truncate()
-            .normalize_or_zero();
-        // This makes the player look towards the impact
-        player.current_direction = -dir;
-        velocity.linvel = dir * STAGGERING_INTENSITY;
-    }
-}
-
-fn demon_boss_collisions(
-    mut q_player: Query<(&mut Velocity, &mut Player, &mut Health, &Transform)>,
-    q_demon_boss: Query<(Entity, &Transform, &DemonBoss), Without<Player>>,
-    q_colliders: Query<&ChildOf, (With<Collider>, Without<DemonBoss>, Without<Player>)>,
-    mut ev_collision_events: EventReader<CollisionEvent>,
-) {
-    let (mut velocity, mut player, mut health, player_transform) = match q_player.single_mut() {
-        Ok(r) => r,
-        Err(_) => return,
-    };
-    let (demon_boss_entity, enemy_transform, demon_boss) = match q_demon_boss.single() {
-        Ok(r) => r,
-        Err(_) => return,
-    };
-
-    for ev in ev_collision_events.read() {
-        let (source, target) = match ev {
-            CollisionEvent::Started(source, target, _) => (source, target),
-            CollisionEvent::Stopped(_, _, _) => continue,
-        };
-
-        let enemy_parent = if &player.collider_entity == source {
-            match q_colliders.get(*target) {
-                Ok(c) => c.parent(),
-                Err(_) => continue,
-            }
-        } else if &player.collider_entity == target {
-            match q_colliders.get(*source) {
-                Ok(c) => c.parent(),
-                Err(_) => continue,
-            }
-        } else {
-            continue;
-        };
-
-        if demon_boss_entity != enemy_parent {
-            continue;
+        if ev.knockback != Vec2::ZERO {
+            // This makes the player look towards the impact
+            player.current_direction = -ev.knockback.normalize();
         }
-
-        health.health -= demon_boss.damage;
-        player.state = PlayerState::Staggering;
-
-        let dir = (player_transform.translation - enemy_transform.translation)
-            .truncate()
-            .normalize_or_zero();
-        // This makes the player look towards the impact
-        player.current_direction = -dir;
-        velocity.linvel = dir * STAGGERING_INTENSITY;
-    }
-}
-
-fn demon_boss_explosion_collisions(
-    mut q_player: Query<(&mut Velocity, &mut Player, &mut Health, &Transform)>,
-    q_explosions: Query<(&Transform, &DemonBossExplosion), Without<Player>>,
-    q_strike_explosions: Query<(&Transform, &DemonBossStrikeExplosion), Without<Player>>,
-    q_colliders: Query<&ChildOf, (With<Collider>, Without<Enemy>, Without<Player>)>,
-    mut ev_collision_events: EventReader<CollisionEvent>,
-) {
-    let (mut velocity, mut player, mut health, player_transform) = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
-    for ev in ev_collision_events.read() {
-        let (source, target) = match ev {
-            CollisionEvent::Started(source, target, _) => (source, target),
-            CollisionEvent::Stopped(_, _, _) => continue,
-        };
-
-        let enemy_parent = if &player.collider_entity == source {
-            match q_colliders.get(*target) {
-                Ok(c) => c.parent(),
-                Err(_) => continue,
-            }
-        } else if &player.collider_entity == target {
-            match q_colliders.get(*source) {
-                Ok(c) => c.parent(),
-                Err(_) => continue,
-            }
-        } else {
-            continue;
-        };
-
-        let (explosion_pos, damage) = if let Ok(r) = q_explosions.get(enemy_parent) {
-            (r.0.translation, r.1.damage)
-        } else if let Ok(r) = q_strike_explosions.get(enemy_parent) {
-            (r.0.translation, r.1.damage)
-        } else {
-            continue;
-        };
-
-        health.health -= damage;
-        player.state = PlayerState::Staggering;
-
-        let dir = (player_transform.translation - explosion_pos)
-            .truncate()
-            .normalize_or_zero();
-        // This makes the player look towards the impact
-        player.current_direction = -dir;
-        velocity.linvel = dir * STAGGERING_INTENSITY;
     }
 }
 
@@ -169,11 +26,8 @@ impl Plugin for PlayerCollisionPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (
-                slime_collisions,
-                demon_boss_collisions,
-                demon_boss_explosion_collisions,
-            )
+            stagger_player
+                .after(apply_damage)
                 .run_if(in_state(GameState::Gaming)),
         );
     }
@@ -14,12 +14,15 @@ pub use state::PlayerState;
 
 use bevy::prelude::*;
 
+use crate::spell::{status::StatusEffects, Faction};
 use crate::world::CHUNK_SIZE;
 
 pub const PLAYER_SPAWN_POS: Vec3 = Vec3::new(2.5 * CHUNK_SIZE, 16.0 + 2.5 * CHUNK_SIZE, 0.0);
 pub const PLAYER_HEALTH: f32 = 10.0;
+const PLAYER_OVERHEAL: f32 = 2.0;
+pub const PLAYER_MANA: f32 = 100.0;
 const STAGGERING_TIME: f32 = 0.25;
-const STAGGERING_INTENSITY: f32 = 200.0;
+const INVULNERABLE_TIME: f32 = 0.75;
 
 pub struct PlayerPlugin;
 
@@ -34,11 +37,13 @@ impl Plugin for PlayerPlugin {
             movement::PlayerMovementPlugin,
             spawn::PlayerSpawnPlugin,
             speed_timer::SpeedTimerPlugin,
+            stats::StatsPlugin,
         ));
     }
 }
 
 #[derive(Component)]
+#[require(Faction = Faction::Player, StatusEffects)]
 pub struct Player {
     pub state: PlayerState,
     pub current_direction: Vec2,
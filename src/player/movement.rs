@@ -1,18 +1,18 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::GameState;
+use crate::{spell::status::StatusEffects, GameState};
 
 use super::input::PlayerInput;
-use super::stats::Stats;
+use super::stats::{Stat, Stats};
 use super::{Player, PlayerState};
 
 fn player_movement(
-    mut q_player: Query<(&mut Velocity, &mut Player, &Stats)>,
+    mut q_player: Query<(&mut Velocity, &mut Player, &Stats, &StatusEffects)>,
     player_input: Res<PlayerInput>,
 ) {
-    let (mut velocity, mut player, stats) = match q_player.single_mut() {
-        Ok(p) => (p.0, p.1, p.2),
+    let (mut velocity, mut player, stats, status_effects) = match q_player.single_mut() {
+        Ok(p) => (p.0, p.1, p.2, p.3),
         Err(_) => return,
     };
 
@@ -35,7 +35,7 @@ fn player_movement(
 
     player.state = PlayerState::Moving;
     player.current_direction = direction;
-    velocity.linvel = direction * stats.move_speed;
+    velocity.linvel = direction * stats.get(Stat::MoveSpeed) * status_effects.slow();
 }
 
 pub struct PlayerMovementPlugin;
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
+use crate::spell::damage::Hurtbox;
+use crate::spell::mana::{Mana, SpellCooldowns};
 use crate::ui::health::{Health, SpawnPlayerHearts};
 use crate::utils::anim_sprite::{AnimationIndices, FrameTimer};
 use crate::world::camera::YSort;
@@ -8,7 +10,9 @@ use crate::{GameAssets, GameState};
 
 use super::audio::StepsTimer;
 use super::stats::Stats;
-use super::{Player, PLAYER_HEALTH, PLAYER_SPAWN_POS};
+use super::{
+    Player, INVULNERABLE_TIME, PLAYER_HEALTH, PLAYER_MANA, PLAYER_OVERHEAL, PLAYER_SPAWN_POS,
+};
 
 fn spawn_player(
     mut commands: Commands,
@@ -22,7 +26,9 @@ fn spawn_player(
             Velocity::zero(),
             Ccd::enabled(),
             Stats::default(),
-            Health::new(PLAYER_HEALTH),
+            Health::new(PLAYER_HEALTH).with_overheal(PLAYER_OVERHEAL),
+            Mana::new(PLAYER_MANA),
+            SpellCooldowns::default(),
             YSort(0.0),
             AnimationIndices { first: 0, last: 5 },
             FrameTimer(Timer::from_seconds(0.085, TimerMode::Repeating)),
@@ -44,6 +50,10 @@ fn spawn_player(
             Collider::ball(4.0),
             ActiveEvents::COLLISION_EVENTS,
             CollisionGroups::default(),
+            Hurtbox {
+                invulnerability: INVULNERABLE_TIME,
+                ..default()
+            },
             Transform::from_translation(Vec3::new(0.0, -5.0, 0.0)),
         ))
         .id();
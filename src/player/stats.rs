@@ -1,12 +1,196 @@
 use bevy::prelude::*;
 
+use crate::{item::platform::TriggerFinalAct, ui::health::Health, GameState};
+
+use super::{Player, PLAYER_HEALTH};
+
+/// Extra max health for the fight against the demon boss.
+const FINAL_ACT_MAX_HEALTH: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+    MoveSpeed,
+    MaxHealth,
+    /// Multiplier for the damage of casted spells.
+    SpellDamage,
+    /// Multiplier for the cooldown of casted spells.
+    CastCooldown,
+    /// How close the player has to get to statues to trigger them.
+    PickupRadius,
+}
+
+/// Where a modifier comes from, so that all of its modifiers can be removed at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatSource {
+    SpeedBoost,
+    FinalAct,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModifierValue {
+    Flat(f32),
+    /// `0.5` adds 50% of the flat value.
+    Percent(f32),
+}
+
+pub struct StatModifier {
+    stat: Stat,
+    value: ModifierValue,
+    source: StatSource,
+    /// `None` is permanent.
+    timer: Option<Timer>,
+}
+
+impl StatModifier {
+    pub fn flat(stat: Stat, value: f32, source: StatSource) -> Self {
+        Self {
+            stat,
+            value: ModifierValue::Flat(value),
+            source,
+            timer: None,
+        }
+    }
+
+    pub fn percent(stat: Stat, value: f32, source: StatSource) -> Self {
+        Self {
+            stat,
+            value: ModifierValue::Percent(value),
+            source,
+            timer: None,
+        }
+    }
+
+    /// Remove the modifier after `duration` seconds.
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.timer = Some(Timer::from_seconds(duration, TimerMode::Once));
+        self
+    }
+}
+
+/// Base values with stacked modifiers on top.
+/// Flat modifiers are added first, then the sum of all percent modifiers is applied.
 #[derive(Component)]
 pub struct Stats {
-    pub move_speed: f32,
+    move_speed: f32,
+    max_health: f32,
+    spell_damage: f32,
+    cast_cooldown: f32,
+    pickup_radius: f32,
+    modifiers: Vec<StatModifier>,
 }
 
 impl Default for Stats {
     fn default() -> Self {
-        Self { move_speed: 150.0 }
+        Self {
+            move_speed: 150.0,
+            max_health: PLAYER_HEALTH,
+            spell_damage: 1.0,
+            cast_cooldown: 1.0,
+            pickup_radius: 64.0,
+            modifiers: Vec::new(),
+        }
+    }
+}
+
+impl Stats {
+    fn base(&self, stat: Stat) -> f32 {
+        match stat {
+            Stat::MoveSpeed => self.move_speed,
+            Stat::MaxHealth => self.max_health,
+            Stat::SpellDamage => self.spell_damage,
+            Stat::CastCooldown => self.cast_cooldown,
+            Stat::PickupRadius => self.pickup_radius,
+        }
+    }
+
+    pub fn get(&self, stat: Stat) -> f32 {
+        let mut flat = self.base(stat);
+        let mut percent = 0.0;
+        for modifier in self.modifiers.iter().filter(|m| m.stat == stat) {
+            match modifier.value {
+                ModifierValue::Flat(value) => flat += value,
+                ModifierValue::Percent(value) => percent += value,
+            }
+        }
+        (flat * (1.0 + percent)).max(0.0)
+    }
+
+    pub fn add_modifier(&mut self, modifier: StatModifier) {
+        self.modifiers.push(modifier);
+    }
+
+    pub fn remove_source(&mut self, source: StatSource) {
+        self.modifiers.retain(|m| m.source != source);
+    }
+}
+
+fn tick_modifiers(time: Res<Time>, mut q_stats: Query<&mut Stats>) {
+    for mut stats in &mut q_stats {
+        if stats.modifiers.iter().all(|m| m.timer.is_none()) {
+            continue;
+        }
+
+        for modifier in &mut stats.modifiers {
+            if let Some(timer) = &mut modifier.timer {
+                timer.tick(time.delta());
+            }
+        }
+        stats
+            .modifiers
+            .retain(|m| !m.timer.as_ref().is_some_and(|t| t.finished()));
+    }
+}
+
+fn grant_final_act_health(
+    mut q_stats: Query<&mut Stats, With<Player>>,
+    mut ev_trigger_final_act: EventReader<TriggerFinalAct>,
+) {
+    if ev_trigger_final_act.is_empty() {
+        return;
+    }
+    ev_trigger_final_act.clear();
+
+    let mut stats = match q_stats.single_mut() {
+        Ok(r) => r,
+        Err(_) => return,
+    };
+
+    stats.remove_source(StatSource::FinalAct);
+    stats.add_modifier(StatModifier::flat(
+        Stat::MaxHealth,
+        FINAL_ACT_MAX_HEALTH,
+        StatSource::FinalAct,
+    ));
+}
+
+/// Hearts gained through a higher max health start out full.
+fn update_max_health(mut q_stats: Query<(&Stats, &mut Health), Changed<Stats>>) {
+    for (stats, mut health) in &mut q_stats {
+        let max_health = stats.get(Stat::MaxHealth);
+        if health.max_health != max_health {
+            let gained = max_health - health.max_health;
+            health.max_health = max_health;
+            if gained > 0.0 {
+                health.heal(gained);
+            }
+        }
+    }
+}
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                tick_modifiers,
+                grant_final_act_health,
+                update_max_health
+                    .after(tick_modifiers)
+                    .after(grant_final_act_health),
+            )
+                .run_if(in_state(GameState::Gaming)),
+        );
     }
 }
@@ -3,13 +3,16 @@ use std::f32::consts::TAU;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::player::Player;
 use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
 use crate::{GameAssets, GameState};
 
-use super::{Spell, SpellCasted};
+use super::{
+    damage::{DamageType, Hitbox, Knockback},
+    Faction, Spell, SpellCasted,
+};
 
 const SCALE: f32 = 2.0;
+const DAMAGE: f32 = 1.0;
 const PULL_INTENSITY: f32 = 400.0;
 const PUSH_INTENSITY: f32 = 400.0;
 
@@ -19,29 +22,19 @@ const AER_TRACTO_OFFSET: Vec3 = Vec3::new(125.0, 0.0, 0.0);
 const AER_PELLO_OFFSET: Vec3 = Vec3::new(75.0, 0.0, 0.0);
 
 #[derive(Component)]
-pub struct AerTracto {
-    pub damage: f32,
-    pub pull_intensity: f32,
-}
-
-impl AerTracto {
-    fn new(pull_intensity: f32) -> Self {
-        Self {
-            damage: 1.0,
-            pull_intensity,
-        }
-    }
-}
+pub struct AerTracto;
 
 fn spawn_air_pull(
     commands: &mut Commands,
     assets: &Res<GameAssets>,
     transform: Transform,
+    faction: Faction,
+    damage: f32,
     pull_intensity: f32,
 ) {
     let entity = commands
         .spawn((
-            AerTracto::new(pull_intensity),
+            AerTracto,
             AnimSprite::new(7, false),
             AnimSpriteTimer::new(0.10),
             Sprite::from_atlas_image(
@@ -59,6 +52,13 @@ fn spawn_air_pull(
         .spawn((
             Collider::ball(15.0),
             Sensor,
+            // Pulls towards the caster, the rotation points away from it.
+            Hitbox {
+                damage,
+                damage_type: DamageType::Air,
+                knockback: Knockback::Directional(-pull_intensity),
+                faction,
+            },
             Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
         ))
         .id();
@@ -69,45 +69,53 @@ fn spawn_air_pull(
 fn spawn_aer_tracto(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<&Transform, With<Player>>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::AerTracto {
-            for i in 0..AER_TRACTO_COUNT {
-                let rot = Quat::from_rotation_z(TAU * i as f32 / AER_TRACTO_COUNT as f32);
-                let offset = rot.mul_vec3(AER_TRACTO_OFFSET);
-                let transform = Transform::from_translation(player_pos + offset)
+            let damage = ev.damage(DAMAGE);
+            let count = ev.count(AER_TRACTO_COUNT);
+            let base_offset = ev.radius(1.0) * AER_TRACTO_OFFSET;
+            for i in 0..count {
+                let rot = Quat::from_rotation_z(TAU * i as f32 / count as f32);
+                let offset = rot.mul_vec3(base_offset);
+                let transform = Transform::from_translation(ev.origin + offset)
                     .with_rotation(rot)
                     .with_scale(Vec3::splat(SCALE));
-                spawn_air_pull(&mut commands, &assets, transform, PULL_INTENSITY);
+                spawn_air_pull(
+                    &mut commands,
+                    &assets,
+                    transform,
+                    ev.faction,
+                    damage,
+                    PULL_INTENSITY,
+                );
 
                 let local_scale = 0.5;
-                let offset = rot.mul_vec3(local_scale * AER_TRACTO_OFFSET);
-                let transform = Transform::from_translation(player_pos + offset)
+                let offset = rot.mul_vec3(local_scale * base_offset);
+                let transform = Transform::from_translation(ev.origin + offset)
                     .with_rotation(rot)
                     .with_scale(Vec3::splat(local_scale * SCALE));
                 spawn_air_pull(
                     &mut commands,
                     &assets,
                     transform,
+                    ev.faction,
+                    damage,
                     PULL_INTENSITY * local_scale,
                 );
 
                 let local_scale = 1.5;
-                let offset = rot.mul_vec3(local_scale * AER_TRACTO_OFFSET);
-                let transform = Transform::from_translation(player_pos + offset)
+                let offset = rot.mul_vec3(local_scale * base_offset);
+                let transform = Transform::from_translation(ev.origin + offset)
                     .with_rotation(rot)
                     .with_scale(Vec3::splat(local_scale * SCALE));
                 spawn_air_pull(
                     &mut commands,
                     &assets,
                     transform,
+                    ev.faction,
+                    damage,
                     PULL_INTENSITY * local_scale,
                 );
             }
@@ -118,45 +126,53 @@ fn spawn_aer_tracto(
 fn spawn_aer_pello(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<&Transform, With<Player>>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::AerPello {
-            for i in 0..AER_PELLO_COUNT {
-                let rot = Quat::from_rotation_z(TAU * i as f32 / AER_PELLO_COUNT as f32);
-                let offset = rot.mul_vec3(-AER_PELLO_OFFSET);
-                let transform = Transform::from_translation(player_pos + offset)
+            let damage = ev.damage(DAMAGE);
+            let count = ev.count(AER_PELLO_COUNT);
+            let base_offset = ev.radius(1.0) * AER_PELLO_OFFSET;
+            for i in 0..count {
+                let rot = Quat::from_rotation_z(TAU * i as f32 / count as f32);
+                let offset = rot.mul_vec3(-base_offset);
+                let transform = Transform::from_translation(ev.origin + offset)
                     .with_rotation(rot)
                     .with_scale(Vec3::splat(SCALE));
-                spawn_air_pull(&mut commands, &assets, transform, PUSH_INTENSITY);
+                spawn_air_pull(
+                    &mut commands,
+                    &assets,
+                    transform,
+                    ev.faction,
+                    damage,
+                    PUSH_INTENSITY,
+                );
 
                 let local_scale = 0.5;
-                let offset = rot.mul_vec3(-local_scale * AER_PELLO_OFFSET);
-                let transform = Transform::from_translation(player_pos + offset)
+                let offset = rot.mul_vec3(-local_scale * base_offset);
+                let transform = Transform::from_translation(ev.origin + offset)
                     .with_rotation(rot)
                     .with_scale(Vec3::splat(local_scale * SCALE));
                 spawn_air_pull(
                     &mut commands,
                     &assets,
                     transform,
+                    ev.faction,
+                    damage,
                     PUSH_INTENSITY * local_scale,
                 );
 
                 let local_scale = 1.5;
-                let offset = rot.mul_vec3(-local_scale * AER_PELLO_OFFSET);
-                let transform = Transform::from_translation(player_pos + offset)
+                let offset = rot.mul_vec3(-local_scale * base_offset);
+                let transform = Transform::from_translation(ev.origin + offset)
                     .with_rotation(rot)
                     .with_scale(Vec3::splat(local_scale * SCALE));
                 spawn_air_pull(
                     &mut commands,
                     &assets,
                     transform,
+                    ev.faction,
+                    damage,
                     PUSH_INTENSITY * local_scale,
                 );
             }
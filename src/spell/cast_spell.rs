@@ -3,12 +3,22 @@ use std::time::Duration;
 use bevy::prelude::*;
 
 use crate::{
-    item::{item_value::item_spell, ActiveItems},
-    player::{Player, PlayerState},
+    item::ActiveItems,
+    player::{
+        stats::{Stat, Stats},
+        Player, PlayerState,
+    },
     ui::text_field::TypingSubmitEvent,
+    GameState,
 };
 
-use super::{debug_spell::DebugSpell, Spell, SpellCasted};
+use super::{
+    debug_spell::DebugSpell,
+    grammar::{parse_incantation, SpellModifiers},
+    mana::{Mana, SpellCooldowns, SpellRejectReason, SpellRejected},
+    registry::{SpellMatching, SpellRegistry},
+    Faction, Spell, SpellCasted,
+};
 
 fn double_j_escape(
     keys: Res<ButtonInput<KeyCode>>,
@@ -39,39 +49,80 @@ fn double_j_escape(
     }
 }
 
-fn is_spell_active(active_items: &Res<ActiveItems>, spell: &Spell) -> bool {
-    if spell == &Spell::Debug || spell == &Spell::Flub {
-        return true;
-    }
-
-    for item in &active_items.0 {
-        if &item_spell(item) == spell {
-            return true;
-        }
-    }
-    false
-}
-
 fn submit_spell(
+    registry: Res<SpellRegistry>,
+    matching: Res<SpellMatching>,
     active_items: Res<ActiveItems>,
     debug_spell: Res<DebugSpell>,
-    mut q_player: Query<&mut Player>,
+    mut q_player: Query<(
+        Entity,
+        &Transform,
+        &Faction,
+        &Stats,
+        &mut Player,
+        &mut Mana,
+        &mut SpellCooldowns,
+    )>,
     mut ev_typing_submit_event: EventReader<TypingSubmitEvent>,
     mut ev_spell_casted: EventWriter<SpellCasted>,
+    mut ev_spell_rejected: EventWriter<SpellRejected>,
 ) {
-    let mut player = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
+    let (entity, transform, faction, stats, mut player, mut mana, mut cooldowns) =
+        match q_player.single_mut() {
+            Ok(p) => p,
+            Err(_) => return,
+        };
 
     for ev in ev_typing_submit_event.read() {
         player.state = PlayerState::Idling;
-        if let Ok(spell) = &ev.value.parse::<Spell>() {
-            if debug_spell.active || is_spell_active(&active_items, spell) {
-                ev_spell_casted.write(SpellCasted {
-                    spell: spell.clone(),
-                });
+        for clause in parse_incantation(&ev.value).clauses {
+            // A clause without a spell only consists of modifiers, e.g. `magna et fulgur`.
+            let (spell, accuracy) = registry
+                .parse(&clause.incantation, matching.threshold)
+                .unwrap_or((Spell::Flub, 0.0));
+            let mut modifiers = SpellModifiers::new(&clause.modifiers);
+            modifiers.damage *= stats.get(Stat::SpellDamage);
+
+            let (mana_cost, cooldown) = registry
+                .entry(&spell)
+                .map(|entry| {
+                    (
+                        entry.mana_cost * modifiers.mana_cost,
+                        entry.cooldown * stats.get(Stat::CastCooldown),
+                    )
+                })
+                .unwrap_or_default();
+
+            let reason = if !debug_spell.active && !registry.is_unlocked(&active_items, &spell) {
+                Some(SpellRejectReason::Locked)
+            } else if cooldowns.is_on_cooldown(&spell) {
+                Some(SpellRejectReason::OnCooldown)
+            } else if mana.mana < mana_cost {
+                Some(SpellRejectReason::NoMana)
+            } else {
+                None
+            };
+
+            if let Some(reason) = reason {
+                ev_spell_rejected.write(SpellRejected { spell, reason });
+                continue;
             }
+
+            mana.mana -= mana_cost;
+            cooldowns.start(spell.clone(), cooldown);
+            ev_spell_casted.write(
+                SpellCasted::new(
+                    spell,
+                    entity,
+                    *faction,
+                    transform.translation,
+                    player.current_direction,
+                )
+                .with_accuracy(accuracy)
+                .with_modifiers(modifiers)
+                .with_typing(ev.metrics)
+                .with_target(clause.target),
+            );
         }
     }
 }
@@ -80,6 +131,9 @@ pub struct CastSpellPlugin;
 
 impl Plugin for CastSpellPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (double_j_escape, submit_spell));
+        app.add_systems(
+            Update,
+            (double_j_escape, submit_spell).run_if(in_state(GameState::Gaming)),
+        );
     }
 }
@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{ui::health::Health, GameState};
+
+use super::{
+    invulnerable::Invulnerable,
+    projectile::{Projectile, ProjectileHit},
+    status::StatusEffects,
+    Faction,
+};
+
+/// The element of a damaging spell, see `Resistances`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageType {
+    /// Contact damage and explosions, no element.
+    Physical,
+    Fire,
+    Lightning,
+    Ice,
+    Air,
+    Void,
+}
+
+/// Multipliers for incoming damage per `DamageType`.
+/// `1.0` takes normal damage, `0.0` is immune.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Resistances {
+    pub physical: f32,
+    pub fire: f32,
+    pub lightning: f32,
+    pub ice: f32,
+    pub air: f32,
+    pub void: f32,
+}
+
+impl Default for Resistances {
+    fn default() -> Self {
+        Self {
+            physical: 1.0,
+            fire: 1.0,
+            lightning: 1.0,
+            ice: 1.0,
+            air: 1.0,
+            void: 1.0,
+        }
+    }
+}
+
+impl Resistances {
+    pub fn apply(&self, damage: f32, damage_type: DamageType) -> f32 {
+        let multiplier = match damage_type {
+            DamageType::Physical => self.physical,
+            DamageType::Fire => self.fire,
+            DamageType::Lightning => self.lightning,
+            DamageType::Ice => self.ice,
+            DamageType::Air => self.air,
+            DamageType::Void => self.void,
+        };
+        damage * multiplier
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Knockback {
+    None,
+    /// Pushes the target away from the owner of the hitbox.
+    Radial(f32),
+    /// Pushes the target along the local x axis of the owner of the hitbox.
+    Directional(f32),
+}
+
+/// Collider that damages hostile `Hurtbox`es.
+/// Needs to be a child of the entity that deals the damage.
+#[derive(Component, Clone, Copy)]
+pub struct Hitbox {
+    pub damage: f32,
+    pub damage_type: DamageType,
+    pub knockback: Knockback,
+    pub faction: Faction,
+}
+
+/// Collider that can be hit by `Hitbox`es.
+/// Needs to be a child of an entity with `Health` and a `Faction`.
+#[derive(Component)]
+pub struct Hurtbox {
+    /// Multiplier for the knockback of incoming hits.
+    pub knockback: f32,
+    /// Seconds of `Invulnerable` after getting hit.
+    pub invulnerability: f32,
+}
+
+impl Default for Hurtbox {
+    fn default() -> Self {
+        Self {
+            knockback: 1.0,
+            invulnerability: 0.0,
+        }
+    }
+}
+
+/// Put on the owner of a `Hitbox` that was spawned by someone else, e.g. the caster of a projectile.
+/// Hits of owners without it are attributed to the owner itself.
+#[derive(Component, Clone, Copy)]
+pub struct Instigator(pub Entity);
+
+/// A `Hitbox` hit a `Hurtbox`, `target` is the owner of the `Hurtbox`.
+#[derive(Event)]
+pub struct DamageEvent {
+    /// Who is responsible for the hit, see `Instigator`.
+    pub instigator: Entity,
+    pub target: Entity,
+    pub damage: f32,
+    pub damage_type: DamageType,
+    /// The new velocity of the target, `Vec2::ZERO` stops it.
+    pub knockback: Vec2,
+}
+
+/// Written by `apply_damage` with the health the target actually lost,
+/// after resistances, armor and shields.
+#[derive(Event)]
+pub struct DamageDealt {
+    pub instigator: Entity,
+    pub target: Entity,
+    pub amount: f32,
+}
+
+fn knockback(knockback: Knockback, source: &Transform, target: &Transform) -> Vec2 {
+    match knockback {
+        Knockback::None => Vec2::ZERO,
+        Knockback::Radial(intensity) => {
+            (target.translation - source.translation)
+                .truncate()
+                .normalize_or_zero()
+                * intensity
+        }
+        Knockback::Directional(intensity) => {
+            source.rotation.mul_vec3(Vec3::X).truncate() * intensity
+        }
+    }
+}
+
+pub fn resolve_hits(
+    mut commands: Commands,
+    q_hitboxes: Query<(&Hitbox, &ChildOf)>,
+    q_hurtboxes: Query<(&Hurtbox, &ChildOf)>,
+    q_targets: Query<(&Transform, &Health, &Faction, Has<Invulnerable>)>,
+    q_transforms: Query<&Transform>,
+    mut q_projectiles: Query<&mut Projectile>,
+    mut ev_collision_events: EventReader<CollisionEvent>,
+    q_instigators: Query<&Instigator>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    mut ev_projectile_hit: EventWriter<ProjectileHit>,
+) {
+    // The inserted `Invulnerable`s only show up next frame.
+    let mut invulnerable_targets = Vec::new();
+
+    for ev in ev_collision_events.read() {
+        let (source, target) = match ev {
+            CollisionEvent::Started(source, target, _) => (*source, *target),
+            CollisionEvent::Stopped(_, _, _) => continue,
+        };
+
+        for (hitbox_entity, hurtbox_entity) in [(source, target), (target, source)] {
+            let (hitbox, hitbox_parent) = match q_hitboxes.get(hitbox_entity) {
+                Ok(h) => h,
+                Err(_) => continue,
+            };
+            let (hurtbox, hurtbox_parent) = match q_hurtboxes.get(hurtbox_entity) {
+                Ok(h) => h,
+                Err(_) => continue,
+            };
+            let target_entity = hurtbox_parent.parent();
+            let (target_transform, health, faction, invulnerable) =
+                match q_targets.get(target_entity) {
+                    Ok(t) => t,
+                    Err(_) => continue,
+                };
+            let source_transform = match q_transforms.get(hitbox_parent.parent()) {
+                Ok(t) => t,
+                Err(_) => continue,
+            };
+
+            if health.health <= 0.0 || !hitbox.faction.is_hostile(faction) {
+                continue;
+            }
+            if invulnerable || invulnerable_targets.contains(&target_entity) {
+                continue;
+            }
+
+            if let Ok(mut projectile) = q_projectiles.get_mut(hitbox_parent.parent()) {
+                if !projectile.hit() {
+                    continue;
+                }
+                ev_projectile_hit.write(ProjectileHit {
+                    projectile: hitbox_parent.parent(),
+                    target: target_entity,
+                });
+            }
+
+            let instigator = q_instigators
+                .get(hitbox_parent.parent())
+                .map_or(hitbox_parent.parent(), |i| i.0);
+            ev_damage.write(DamageEvent {
+                instigator,
+                target: target_entity,
+                damage: hitbox.damage,
+                damage_type: hitbox.damage_type,
+                knockback: hurtbox.knockback
+                    * knockback(hitbox.knockback, source_transform, target_transform),
+            });
+
+            if hurtbox.invulnerability > 0.0 {
+                commands
+                    .entity(target_entity)
+                    .insert(Invulnerable::new(hurtbox.invulnerability));
+                invulnerable_targets.push(target_entity);
+            }
+        }
+    }
+}
+
+pub fn apply_damage(
+    mut q_targets: Query<(
+        &mut Health,
+        Option<&mut StatusEffects>,
+        Option<&Resistances>,
+        Option<&mut Velocity>,
+    )>,
+    mut ev_damage: EventReader<DamageEvent>,
+    mut ev_damage_dealt: EventWriter<DamageDealt>,
+) {
+    for ev in ev_damage.read() {
+        let (mut health, status_effects, resistances, velocity) = match q_targets.get_mut(ev.target)
+        {
+            Ok(t) => t,
+            Err(_) => continue,
+        };
+
+        let dealt =
+            health.damage(resistances.map_or(ev.damage, |r| r.apply(ev.damage, ev.damage_type)));
+        ev_damage_dealt.write(DamageDealt {
+            instigator: ev.instigator,
+            target: ev.target,
+            amount: dealt,
+        });
+        if let Some(mut status_effects) = status_effects {
+            status_effects.inflict(ev.damage_type);
+        }
+        if let Some(mut velocity) = velocity {
+            velocity.linvel = ev.knockback;
+        }
+    }
+}
+
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (resolve_hits, apply_damage.after(resolve_hits)).run_if(in_state(GameState::Gaming)),
+        )
+        .add_event::<DamageEvent>()
+        .add_event::<DamageDealt>();
+    }
+}
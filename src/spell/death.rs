@@ -5,15 +5,17 @@ use rand::{thread_rng, Rng};
 use bevy::prelude::*;
 
 use crate::{
-    enemy::Enemy,
-    player::Player,
     ui::health::Health,
     utils::anim_sprite::{AnimSprite, AnimSpriteTimer},
     world::camera::YSort,
     GameAssets, GameState,
 };
 
-use super::{Spell, SpellCasted};
+use super::{
+    damage::{DamageType, Resistances},
+    invulnerable::Invulnerable,
+    Faction, Spell, SpellCasted,
+};
 
 const DAMAGE: f32 = 999.0;
 const SCALE: f32 = 2.0;
@@ -25,6 +27,7 @@ const SCREEN_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 1.0);
 
 #[derive(Component)]
 struct Death {
+    faction: Faction,
     timer: Timer,
     timeout: bool,
 }
@@ -35,8 +38,11 @@ struct ScreenEffect {
     disabled: bool,
 }
 
+/// Kill everything that is hostile to `faction`.
 #[derive(Event)]
-struct KillAll;
+struct KillAll {
+    faction: Faction,
+}
 
 impl Default for ScreenEffect {
     fn default() -> Self {
@@ -47,9 +53,10 @@ impl Default for ScreenEffect {
     }
 }
 
-impl Default for Death {
-    fn default() -> Self {
+impl Death {
+    fn new(faction: Faction) -> Self {
         Self {
+            faction,
             timer: Timer::from_seconds(SPRITES as f32 * SPRITE_TIME, TimerMode::Once),
             timeout: false,
         }
@@ -59,21 +66,15 @@ impl Default for Death {
 fn spawn_deaths(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<&Transform, With<Player>>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
-
     let mut rng = thread_rng();
     let offset = Quat::from_rotation_z(rng.gen_range(0.0..TAU)).mul_vec3(Vec3::X) * DISTANCE;
 
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::Death {
             commands.spawn((
-                Death::default(),
+                Death::new(ev.faction),
                 YSort(100.0),
                 AnimSprite::new(SPRITES, true),
                 AnimSpriteTimer::new(SPRITE_TIME),
@@ -84,7 +85,7 @@ fn spawn_deaths(
                         ..default()
                     },
                 ),
-                Transform::from_translation(pos + offset).with_scale(Vec3::splat(SCALE)),
+                Transform::from_translation(ev.origin + offset).with_scale(Vec3::splat(SCALE)),
             ));
         }
     }
@@ -128,7 +129,9 @@ fn despawn_deaths(
             continue;
         }
 
-        ev_kill_all.write(KillAll);
+        ev_kill_all.write(KillAll {
+            faction: death.faction,
+        });
         commands.entity(entity).despawn();
     }
 }
@@ -166,13 +169,15 @@ fn despawn_screen_effects(
     }
 }
 
-fn kill_all_enemies(
-    mut q_enemies: Query<&mut Health, With<Enemy>>,
+fn kill_all_hostiles(
+    mut q_healths: Query<(&mut Health, &Faction, Option<&Resistances>), Without<Invulnerable>>,
     mut ev_kill_all: EventReader<KillAll>,
 ) {
-    for _ in ev_kill_all.read() {
-        for mut health in &mut q_enemies {
-            health.health -= DAMAGE;
+    for ev in ev_kill_all.read() {
+        for (mut health, faction, resistances) in &mut q_healths {
+            if ev.faction.is_hostile(faction) {
+                health.damage(resistances.map_or(DAMAGE, |r| r.apply(DAMAGE, DamageType::Void)));
+            }
         }
     }
 }
@@ -191,7 +196,7 @@ impl Plugin for DeathPlugin {
                 despawn_deaths,
                 spawn_screen_effects,
                 despawn_screen_effects,
-                kill_all_enemies,
+                kill_all_hostiles,
             )
                 .run_if(in_state(GameState::Gaming)),
         )
@@ -1,75 +1,56 @@
-use std::f32::consts::{PI, TAU};
+use std::f32::consts::TAU;
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::enemy::Enemy;
-use crate::player::Player;
 use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
 use crate::utils::quat_from_vec2;
 use crate::{GameAssets, GameState};
 
-use super::{Spell, SpellCasted};
+use super::{
+    damage::{DamageType, Hitbox, Knockback},
+    projectile::{Homing, Projectile},
+    targeting::Targets,
+    Faction, Spell, SpellCasted,
+};
 
 const SPEED: f32 = 300.0;
+const KNOCKBACK: f32 = 100.0;
 const SCALE: f32 = 1.25;
-const SCALE_TIME: f32 = 0.35;
+const LIFETIME: f32 = 5.0;
+const HOMING_TURN_RATE: f32 = 1.5;
 const INFERNO_COUNT: usize = 25;
+const IGNIS_COUNT: usize = 5;
+const IGNIS_SPREAD: f32 = 0.08;
 
 #[derive(Component)]
-pub struct Fireball {
-    disabled: bool,
-    piercing: bool,
-    pub damage: f32,
-    timer: Timer,
-}
-
-impl Default for Fireball {
-    fn default() -> Self {
-        Self {
-            disabled: false,
-            piercing: false,
-            damage: 5.0,
-            timer: Timer::from_seconds(5.0, TimerMode::Once),
-        }
-    }
-}
-
-impl Fireball {
-    pub fn disable(&mut self) {
-        if !self.piercing {
-            self.disabled = true;
-        }
-    }
-
-    pub fn disabled(&self) -> bool {
-        self.disabled
-    }
-}
+pub struct Fireball;
 
 fn spawn_fireball(
     commands: &mut Commands,
     assets: &Res<GameAssets>,
     transform: Transform,
+    faction: Faction,
     damage: f32,
-    piercing: bool,
+    projectile: Projectile,
 ) {
+    let mut sprite = Sprite::from_atlas_image(
+        assets.fireball_texture.clone(),
+        TextureAtlas {
+            layout: assets.fireball_layout.clone(),
+            ..default()
+        },
+    );
+    // The texture points to the left, but projectiles fly along their positive x axis.
+    sprite.flip_x = true;
+
     let entity = commands
         .spawn((
-            Fireball {
-                damage,
-                piercing,
-                ..default()
-            },
+            Fireball,
+            projectile,
             AnimSprite::new(60, true),
             AnimSpriteTimer::new(0.05),
-            Sprite::from_atlas_image(
-                assets.fireball_texture.clone(),
-                TextureAtlas {
-                    layout: assets.fireball_layout.clone(),
-                    ..default()
-                },
-            ),
+            sprite,
             transform,
         ))
         .id();
@@ -78,7 +59,13 @@ fn spawn_fireball(
         .spawn((
             Collider::ball(5.0),
             Sensor,
-            Transform::from_translation(Vec3::new(-25.0, 0.0, 0.0)),
+            Hitbox {
+                damage,
+                damage_type: DamageType::Fire,
+                knockback: Knockback::Radial(KNOCKBACK),
+                faction,
+            },
+            Transform::from_translation(Vec3::new(25.0, 0.0, 0.0)),
         ))
         .id();
 
@@ -88,34 +75,33 @@ fn spawn_fireball(
 fn spawn_fireballs(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<(&Transform, &Player)>,
-    q_enemies: Query<&Transform, With<Enemy>>,
+    targets: Targets,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let (player_transform, player) = match q_player.single() {
-        Ok(p) => (p.0, p.1),
-        Err(_) => return,
-    };
-
-    let mut closest_enemy = Vec2::INFINITY;
-    for enemy_transform in &q_enemies {
-        if enemy_transform.translation.truncate().length_squared() < closest_enemy.length_squared()
-        {
-            closest_enemy = enemy_transform.translation.truncate();
-        }
-    }
-    let rot = if closest_enemy == Vec2::INFINITY {
-        quat_from_vec2(Vec2::new(-player.current_direction.x, 0.0))
-    } else {
-        quat_from_vec2(-closest_enemy + player_transform.translation.truncate())
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::Fireball {
-            let transform = Transform::from_translation(player_transform.translation)
-                .with_scale(Vec3::splat(SCALE))
-                .with_rotation(rot);
-            spawn_fireball(&mut commands, &assets, transform, 5.0, false);
+            let dir = targets.direction(ev);
+            let transform = Transform::from_translation(ev.origin)
+                .with_scale(Vec3::splat(ev.radius(SCALE)))
+                .with_rotation(quat_from_vec2(dir));
+            // Only home in on enemies when a target was named, e.g. `fireball debilis`.
+            let homing = ev.target.and(targets.target(ev)).map(|target| Homing {
+                target,
+                turn_rate: HOMING_TURN_RATE,
+            });
+            spawn_fireball(
+                &mut commands,
+                &assets,
+                transform,
+                ev.faction,
+                ev.damage(5.0),
+                Projectile {
+                    speed: SPEED,
+                    lifetime: Timer::from_seconds(LIFETIME, TimerMode::Once),
+                    homing,
+                    ..default()
+                },
+            );
         }
     }
 }
@@ -123,39 +109,33 @@ fn spawn_fireballs(
 fn spawn_ignis_pila(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<(&Transform, &Player)>,
-    q_enemies: Query<&Transform, With<Enemy>>,
+    targets: Targets,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let (player_pos, player) = match q_player.single() {
-        Ok(p) => (p.0.translation, p.1),
-        Err(_) => return,
-    };
-
-    let mut closest_enemy = Vec2::INFINITY;
-    for enemy_transform in &q_enemies {
-        if enemy_transform.translation.truncate().length_squared() < closest_enemy.length_squared()
-        {
-            closest_enemy = enemy_transform.translation.truncate();
-        }
-    }
-    let angle = if closest_enemy == Vec2::INFINITY {
-        if player.current_direction.x < 0.0 {
-            0.0
-        } else {
-            PI
-        }
-    } else {
-        Vec2::X.angle_to(-closest_enemy + player_pos.truncate())
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::IgnisPila {
-            for offset in [0.0, 0.08, -0.08, 0.16, -0.16] {
-                let transform = Transform::from_translation(player_pos)
+            let dir = targets.direction(ev);
+            let angle = Vec2::X.angle_to(dir);
+            let count = ev.count(IGNIS_COUNT);
+            for i in 0..count {
+                let offset = (i as f32 - (count - 1) as f32 / 2.0) * IGNIS_SPREAD;
+                let transform = Transform::from_translation(ev.origin)
                     .with_scale(Vec3::ZERO)
                     .with_rotation(Quat::from_rotation_z(angle + offset));
-                spawn_fireball(&mut commands, &assets, transform, 3.0, true);
+                spawn_fireball(
+                    &mut commands,
+                    &assets,
+                    transform,
+                    ev.faction,
+                    ev.damage(3.0),
+                    Projectile {
+                        speed: SPEED,
+                        lifetime: Timer::from_seconds(LIFETIME, TimerMode::Once),
+                        pierce: None,
+                        scale_in: Some(ev.radius(SCALE)),
+                        ..default()
+                    },
+                );
             }
         }
     }
@@ -164,73 +144,41 @@ fn spawn_ignis_pila(
 fn spawn_inferno_pila(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<&Transform, With<Player>>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::InfernoPila {
-            for i in 0..INFERNO_COUNT {
-                let transform = Transform::from_translation(player_pos)
+            let count = ev.count(INFERNO_COUNT);
+            for i in 0..count {
+                let transform = Transform::from_translation(ev.origin)
                     .with_scale(Vec3::ZERO)
-                    .with_rotation(Quat::from_rotation_z(TAU * i as f32 / INFERNO_COUNT as f32));
-                spawn_fireball(&mut commands, &assets, transform, 2.0, true);
+                    .with_rotation(Quat::from_rotation_z(TAU * i as f32 / count as f32));
+                spawn_fireball(
+                    &mut commands,
+                    &assets,
+                    transform,
+                    ev.faction,
+                    ev.damage(2.0),
+                    Projectile {
+                        speed: SPEED,
+                        lifetime: Timer::from_seconds(LIFETIME, TimerMode::Once),
+                        pierce: None,
+                        scale_in: Some(ev.radius(SCALE)),
+                        ..default()
+                    },
+                );
             }
         }
     }
 }
 
-fn scale_fireballs(time: Res<Time>, mut q_fireballs: Query<&mut Transform, With<Fireball>>) {
-    for mut transform in &mut q_fireballs {
-        if transform.scale.x < SCALE {
-            transform.scale += Vec3::ONE * SCALE / SCALE_TIME * time.delta_secs();
-        }
-    }
-}
-
-fn move_fireballs(time: Res<Time>, mut q_fireballs: Query<&mut Transform, With<Fireball>>) {
-    for mut transform in &mut q_fireballs {
-        let dir = -transform.local_x();
-        transform.translation += dir * SPEED * time.delta_secs();
-    }
-}
-
-fn tick_fireball_timers(time: Res<Time>, mut q_fireballs: Query<&mut Fireball>) {
-    for mut fireball in &mut q_fireballs {
-        fireball.timer.tick(time.delta());
-        if fireball.timer.just_finished() {
-            fireball.disabled = true;
-        }
-    }
-}
-
-fn despawn_fireballs(mut commands: Commands, q_fireballs: Query<(Entity, &Fireball)>) {
-    for (entity, fireball) in &q_fireballs {
-        if fireball.disabled {
-            commands.entity(entity).despawn();
-        }
-    }
-}
-
 pub struct FireballPlugin;
 
 impl Plugin for FireballPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (
-                spawn_fireballs,
-                spawn_ignis_pila,
-                spawn_inferno_pila,
-                despawn_fireballs,
-                scale_fireballs,
-                move_fireballs,
-                tick_fireball_timers,
-            )
+            (spawn_fireballs, spawn_ignis_pila, spawn_inferno_pila)
                 .run_if(in_state(GameState::Gaming)),
         );
     }
@@ -2,7 +2,6 @@ use bevy::math::vec2;
 use bevy::prelude::*;
 
 use crate::{
-    player::Player,
     ui::world_text::{SpawnWorldText, WorldText},
     GameState,
 };
@@ -10,15 +9,9 @@ use crate::{
 use super::{Spell, SpellCasted};
 
 fn spawn_deaths(
-    q_player: Query<&Transform, With<Player>>,
     mut ev_spell_casted: EventReader<SpellCasted>,
     mut ev_spawn_world_text: EventWriter<SpawnWorldText>,
 ) {
-    let pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::Flub {
             ev_spawn_world_text.write(SpawnWorldText {
@@ -26,7 +19,7 @@ fn spawn_deaths(
                     offset: Vec3::new(0.0, 20.0, 10.0),
                     ..default()
                 },
-                pos,
+                pos: ev.origin,
                 content: "FLUB".to_string(),
             });
 
@@ -59,17 +52,39 @@ fn spawn_deaths(
                     alpha_curve,
                     ..default()
                 },
-                pos,
+                pos: ev.origin,
                 content: "Press 'H' for help".to_string(),
             });
         }
     }
 }
 
+fn spawn_fizzles(
+    mut ev_spell_casted: EventReader<SpellCasted>,
+    mut ev_spawn_world_text: EventWriter<SpawnWorldText>,
+) {
+    for ev in ev_spell_casted.read() {
+        if ev.spell != Spell::Flub && ev.accuracy < 1.0 {
+            ev_spawn_world_text.write(SpawnWorldText {
+                world_text: WorldText {
+                    offset: Vec3::new(0.0, 20.0, 10.0),
+                    font_scale: 8.0,
+                    ..default()
+                },
+                pos: ev.origin,
+                content: format!("fizzle {:.0}%", ev.accuracy * 100.0),
+            });
+        }
+    }
+}
+
 pub struct FlubPlugin;
 
 impl Plugin for FlubPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (spawn_deaths,).run_if(in_state(GameState::Gaming)));
+        app.add_systems(
+            Update,
+            (spawn_deaths, spawn_fizzles).run_if(in_state(GameState::Gaming)),
+        );
     }
 }
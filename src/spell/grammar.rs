@@ -0,0 +1,150 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+use super::targeting::TargetMode;
+
+/// Joins multiple spells into one incantation, e.g. `ignis pila et fulgur`.
+pub const CONJUNCTION: &str = "et";
+
+const MODIFIER_SCALE: f32 = 1.5;
+
+/// Words that can be put in front of a spell to strengthen it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Modifier {
+    /// More damage.
+    Magna,
+    /// More projectiles.
+    Multa,
+    /// Larger radius.
+    Lata,
+}
+
+impl Modifier {
+    pub const ALL: [Modifier; 3] = [Modifier::Magna, Modifier::Multa, Modifier::Lata];
+
+    pub fn word(&self) -> &'static str {
+        match self {
+            Modifier::Magna => "magna",
+            Modifier::Multa => "multa",
+            Modifier::Lata => "lata",
+        }
+    }
+
+    pub fn from_word(word: &str) -> Option<Self> {
+        Modifier::ALL.into_iter().find(|m| m.word() == word)
+    }
+}
+
+/// A single spell of an incantation together with its modifiers and target,
+/// e.g. `magna fireball debilis`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellClause {
+    pub modifiers: Vec<Modifier>,
+    pub incantation: String,
+    pub target: Option<TargetMode>,
+}
+
+/// incantation := clause (`et` clause)*
+/// clause := modifier* spell target?
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Incantation {
+    pub clauses: Vec<SpellClause>,
+}
+
+/// Multipliers that the spells apply to their base values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpellModifiers {
+    pub count: f32,
+    pub damage: f32,
+    pub radius: f32,
+    pub mana_cost: f32,
+}
+
+impl Default for SpellModifiers {
+    fn default() -> Self {
+        Self {
+            count: 1.0,
+            damage: 1.0,
+            radius: 1.0,
+            mana_cost: 1.0,
+        }
+    }
+}
+
+impl SpellModifiers {
+    pub fn new(modifiers: &[Modifier]) -> Self {
+        let mut result = Self::default();
+        for modifier in modifiers {
+            match modifier {
+                Modifier::Magna => result.damage *= MODIFIER_SCALE,
+                Modifier::Multa => result.count *= MODIFIER_SCALE,
+                Modifier::Lata => result.radius *= MODIFIER_SCALE,
+            }
+            result.mana_cost *= MODIFIER_SCALE;
+        }
+        result
+    }
+}
+
+/// Case fold and strip diacritics so that e.g. `Fülgur` matches `fulgur`.
+pub fn normalize(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+    {
+        match c {
+            'ß' => result.push_str("ss"),
+            'æ' => result.push_str("ae"),
+            'œ' => result.push_str("oe"),
+            'ø' => result.push('o'),
+            'ł' => result.push('l'),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+fn parse_clause(words: &[&str]) -> Option<SpellClause> {
+    let mut modifiers = Vec::new();
+    let mut rest = words;
+    while let Some((word, tail)) = rest.split_first() {
+        match Modifier::from_word(word) {
+            Some(modifier) => modifiers.push(modifier),
+            None => break,
+        }
+        rest = tail;
+    }
+
+    if modifiers.is_empty() && rest.is_empty() {
+        return None;
+    }
+
+    let mut target = None;
+    if let Some((word, head)) = rest.split_last() {
+        if !head.is_empty() {
+            target = TargetMode::from_word(word);
+            if target.is_some() {
+                rest = head;
+            }
+        }
+    }
+
+    Some(SpellClause {
+        modifiers,
+        incantation: rest.join(" "),
+        target,
+    })
+}
+
+/// Split the typed text into spell clauses.
+/// A clause that only consists of modifiers has an empty `incantation`.
+pub fn parse_incantation(input: &str) -> Incantation {
+    let input = normalize(input);
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let clauses = words
+        .split(|word| *word == CONJUNCTION)
+        .filter_map(parse_clause)
+        .collect();
+    Incantation { clauses }
+}
@@ -3,49 +3,50 @@ use std::f32::consts::TAU;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::player::Player;
+use crate::ui::health::Health;
 use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
 use crate::world::camera::YSort;
 use crate::{GameAssets, GameState};
 
-use super::{Spell, SpellCasted};
+use super::{
+    damage::{DamageType, Hitbox, Knockback},
+    projectile::{despawn_projectiles, Projectile, ProjectileBlocker},
+    Faction, Spell, SpellCasted,
+};
 
 const SCALE: f32 = 1.5;
 const SCUTUM_GLACIEI_COUNT: usize = 10;
-const DISTANCE_FROM_PLAYER: f32 = 75.0;
+const DISTANCE_FROM_CASTER: f32 = 75.0;
 const DELTA_ROTATION: f32 = TAU / 4.0;
 const TIME: f32 = 10.0;
+const KNOCKBACK: f32 = 100.0;
+const SHIELD: f32 = 2.0;
 
 #[derive(Component)]
 pub struct Icicle {
-    timer: Timer,
-    pub disabled: bool,
-    pub damage: f32,
+    /// The entity the icicles circle around.
+    caster: Entity,
+    distance: f32,
 }
 
-impl Default for Icicle {
-    fn default() -> Self {
-        Self {
-            timer: Timer::from_seconds(TIME, TimerMode::Once),
-            disabled: false,
-            damage: 1.0,
-        }
-    }
-}
-
-fn tick_timers(time: Res<Time>, mut q_icicles: Query<&mut Icicle>) {
-    for mut icicle in &mut q_icicles {
-        icicle.timer.tick(time.delta());
-        if icicle.timer.just_finished() {
-            icicle.disabled = true;
-        }
-    }
-}
-
-fn spawn_icicle(commands: &mut Commands, assets: &Res<GameAssets>, transform: Transform) {
+fn spawn_icicle(
+    commands: &mut Commands,
+    assets: &Res<GameAssets>,
+    transform: Transform,
+    caster: Entity,
+    faction: Faction,
+    damage: f32,
+    distance: f32,
+) {
     let entity = commands
         .spawn((
-            Icicle::default(),
+            Icicle { caster, distance },
+            // Icicles don't fly, they circle around the caster, see `move_icicles`.
+            Projectile {
+                lifetime: Timer::from_seconds(TIME, TimerMode::Once),
+                pierce: None,
+                ..default()
+            },
             YSort(10.0),
             AnimSprite::new(30, true),
             AnimSpriteTimer::new(0.05),
@@ -64,6 +65,14 @@ fn spawn_icicle(commands: &mut Commands, assets: &Res<GameAssets>, transform: Tr
         .spawn((
             Collider::cuboid(25.0, 4.0),
             Sensor,
+            // Icicles point away from their caster.
+            Hitbox {
+                damage,
+                damage_type: DamageType::Ice,
+                knockback: Knockback::Directional(KNOCKBACK),
+                faction,
+            },
+            ProjectileBlocker,
             Transform::from_translation(Vec3::new(5.0, 0.0, 0.0)),
         ))
         .id();
@@ -74,24 +83,33 @@ fn spawn_icicle(commands: &mut Commands, assets: &Res<GameAssets>, transform: Tr
 fn spawn_scutum_glaciei(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<&Transform, With<Player>>,
+    mut q_healths: Query<&mut Health>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::ScutumGlaciei {
-            for i in 0..SCUTUM_GLACIEI_COUNT {
-                let rot = Quat::from_rotation_z(TAU * i as f32 / SCUTUM_GLACIEI_COUNT as f32);
-                let transform = Transform::from_translation(
-                    player_pos + rot.mul_vec3(Vec3::X) * DISTANCE_FROM_PLAYER,
-                )
-                .with_scale(Vec3::splat(SCALE))
-                .with_rotation(rot);
-                spawn_icicle(&mut commands, &assets, transform);
+            // The shield lasts as long as the icicles do.
+            if let Ok(mut health) = q_healths.get_mut(ev.caster) {
+                health.add_shield(ev.damage(SHIELD), TIME);
+            }
+
+            let count = ev.count(SCUTUM_GLACIEI_COUNT);
+            let distance = ev.radius(DISTANCE_FROM_CASTER);
+            for i in 0..count {
+                let rot = Quat::from_rotation_z(TAU * i as f32 / count as f32);
+                let transform =
+                    Transform::from_translation(ev.origin + rot.mul_vec3(Vec3::X) * distance)
+                        .with_scale(Vec3::splat(SCALE))
+                        .with_rotation(rot);
+                spawn_icicle(
+                    &mut commands,
+                    &assets,
+                    transform,
+                    ev.caster,
+                    ev.faction,
+                    ev.damage(1.0),
+                    distance,
+                );
             }
         }
     }
@@ -113,31 +131,33 @@ fn spawn_icicle_shatter(commands: &mut Commands, assets: &Res<GameAssets>, posit
     ));
 }
 
-fn despawn_icicles(
+fn shatter_icicles(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_icicles: Query<(Entity, &Transform, &Icicle)>,
+    q_icicles: Query<(&Transform, &Projectile), With<Icicle>>,
 ) {
-    for (entity, transform, icicle) in &q_icicles {
-        if icicle.disabled {
+    for (transform, projectile) in &q_icicles {
+        if projectile.disabled {
             spawn_icicle_shatter(&mut commands, &assets, transform.translation);
-            commands.entity(entity).despawn();
         }
     }
 }
 
 fn move_icicles(
-    q_player: Query<&Transform, With<Player>>,
-    mut q_icicles: Query<&mut Transform, (With<Icicle>, Without<Player>)>,
+    q_casters: Query<&Transform, Without<Icicle>>,
+    mut q_icicles: Query<(&mut Transform, &mut Projectile, &Icicle)>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation,
-        Err(_) => return,
-    };
+    for (mut transform, mut projectile, icicle) in &mut q_icicles {
+        let caster_pos = match q_casters.get(icicle.caster) {
+            Ok(c) => c.translation,
+            Err(_) => {
+                projectile.disabled = true;
+                continue;
+            }
+        };
 
-    for mut transform in &mut q_icicles {
-        let offset = transform.rotation.mul_vec3(Vec3::X) * DISTANCE_FROM_PLAYER;
-        transform.translation = player_pos + offset;
+        let offset = transform.rotation.mul_vec3(Vec3::X) * icicle.distance;
+        transform.translation = caster_pos + offset;
     }
 }
 
@@ -153,13 +173,13 @@ impl Plugin for IciclePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (
-                tick_timers,
-                spawn_scutum_glaciei,
-                despawn_icicles,
-                move_icicles,
-                rotate_icicles,
-            )
+            (spawn_scutum_glaciei, move_icicles, rotate_icicles)
+                .run_if(in_state(GameState::Gaming)),
+        )
+        .add_systems(
+            PostUpdate,
+            shatter_icicles
+                .before(despawn_projectiles)
                 .run_if(in_state(GameState::Gaming)),
         );
     }
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::GameState;
+
+use super::{Spell, SpellCasted};
+
+const BLINK_TIME: f32 = 0.1;
+
+/// Ignores all incoming damage, see `resolve_hits`.
+#[derive(Component)]
+pub struct Invulnerable {
+    /// `None` lasts until the component is removed.
+    timer: Option<Timer>,
+    blink_timer: Option<Timer>,
+}
+
+impl Invulnerable {
+    /// Lasts for `duration` seconds and makes the entity blink.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Some(Timer::from_seconds(duration, TimerMode::Once)),
+            blink_timer: Some(Timer::from_seconds(BLINK_TIME, TimerMode::Repeating)),
+        }
+    }
+
+    /// Used by god mode.
+    pub fn permanent() -> Self {
+        Self {
+            timer: None,
+            blink_timer: None,
+        }
+    }
+
+    pub fn without_blink(mut self) -> Self {
+        self.blink_timer = None;
+        self
+    }
+
+    pub fn is_permanent(&self) -> bool {
+        self.timer.is_none()
+    }
+}
+
+fn tick_invulnerables(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_invulnerables: Query<(Entity, &mut Invulnerable, &mut Visibility)>,
+) {
+    for (entity, mut invulnerable, mut visibility) in &mut q_invulnerables {
+        if let Some(blink_timer) = &mut invulnerable.blink_timer {
+            blink_timer.tick(time.delta());
+            if blink_timer.just_finished() {
+                visibility.toggle_inherited_hidden();
+            }
+        }
+
+        let Some(timer) = &mut invulnerable.timer else {
+            continue;
+        };
+        timer.tick(time.delta());
+        if timer.just_finished() {
+            *visibility = Visibility::Inherited;
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+}
+
+fn toggle_god_mode(
+    mut commands: Commands,
+    mut q_casters: Query<(Option<&Invulnerable>, &mut Visibility)>,
+    mut ev_spell_casted: EventReader<SpellCasted>,
+) {
+    for ev in ev_spell_casted.read() {
+        if ev.spell == Spell::GodMode {
+            let (invulnerable, mut visibility) = match q_casters.get_mut(ev.caster) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
+            *visibility = Visibility::Inherited;
+            if invulnerable.is_some_and(|i| i.is_permanent()) {
+                commands.entity(ev.caster).remove::<Invulnerable>();
+            } else {
+                commands.entity(ev.caster).insert(Invulnerable::permanent());
+            }
+        }
+    }
+}
+
+pub struct InvulnerablePlugin;
+
+impl Plugin for InvulnerablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (tick_invulnerables, toggle_god_mode).run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
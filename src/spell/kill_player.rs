@@ -1,21 +1,15 @@
 use bevy::prelude::*;
 
-use crate::{player::Player, ui::health::Health, GameState};
+use crate::{ui::health::Health, GameState};
 
 use super::{Spell, SpellCasted};
 
-fn kill_player(
-    mut q_player: Query<&mut Health, With<Player>>,
-    mut ev_spell_casted: EventReader<SpellCasted>,
-) {
-    let mut player_health = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
+fn kill_player(mut q_healths: Query<&mut Health>, mut ev_spell_casted: EventReader<SpellCasted>) {
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::KillPlayer {
-            player_health.health = 0.0;
+            if let Ok(mut health) = q_healths.get_mut(ev.caster) {
+                health.health = 0.0;
+            }
         }
     }
 }
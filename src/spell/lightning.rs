@@ -1,17 +1,20 @@
 use rand::rngs::ThreadRng;
-use rand::seq::IteratorRandom;
 use rand::Rng;
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::enemy::Enemy;
 use crate::utils::anim_sprite::{AnimSprite, AnimSpriteTimer};
 use crate::{GameAssets, GameState};
 
-use super::{Spell, SpellCasted};
+use super::{
+    damage::{DamageType, Hitbox, Knockback},
+    targeting::Targets,
+    Faction, Spell, SpellCasted,
+};
 
 const SCALE: f32 = 3.0;
+const DAMAGE: f32 = 3.0;
 const FULGUR_COUNT: usize = 3;
 
 const SPRITES_COUNT: usize = 12;
@@ -22,20 +25,18 @@ const POSITION_OFFSET: Vec3 = Vec3::new(0.0, SPRITE_HEIGHT_HALF * SCALE, 0.0);
 const RAND_OFFSET: f32 = 10.0;
 
 #[derive(Component)]
-pub struct Lightning {
-    pub damage: f32,
-}
-
-impl Default for Lightning {
-    fn default() -> Self {
-        Self { damage: 3.0 }
-    }
-}
-
-fn spawn_lightning(commands: &mut Commands, assets: &Res<GameAssets>, transform: Transform) {
+pub struct Lightning;
+
+fn spawn_lightning(
+    commands: &mut Commands,
+    assets: &Res<GameAssets>,
+    transform: Transform,
+    faction: Faction,
+    damage: f32,
+) {
     let entity = commands
         .spawn((
-            Lightning::default(),
+            Lightning,
             AnimSprite::new(SPRITES_COUNT, false),
             AnimSpriteTimer::new(SPRITES_TIME),
             Sprite::from_atlas_image(
@@ -53,6 +54,12 @@ fn spawn_lightning(commands: &mut Commands, assets: &Res<GameAssets>, transform:
         .spawn((
             Collider::ball(5.0),
             Sensor,
+            Hitbox {
+                damage,
+                damage_type: DamageType::Lightning,
+                knockback: Knockback::None,
+                faction,
+            },
             Transform::from_translation(Vec3::new(0.0, -SPRITE_HEIGHT_HALF, 0.0)),
         ))
         .id();
@@ -63,40 +70,45 @@ fn spawn_lightning(commands: &mut Commands, assets: &Res<GameAssets>, transform:
 fn spawn_fulgur(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_enemies: Query<&Transform, With<Enemy>>,
+    targets: Targets,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    fn get_pos(rng: &mut ThreadRng, t: &Transform) -> Transform {
+    fn get_pos(rng: &mut ThreadRng, pos: Vec3) -> Transform {
         Transform::from_translation(
-            t.translation
-                + POSITION_OFFSET
+            pos + POSITION_OFFSET
                 + Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0), 0.0) * RAND_OFFSET,
         )
         .with_scale(Vec3::splat(SCALE))
     }
 
     for ev in ev_spell_casted.read() {
-        // The sum of the for loops will always equal `FULGUR_COUNT`.
+        // The sum of the for loops will always equal the (scaled) `FULGUR_COUNT`.
         // So the runtime of this is exactly `O(FULGUR_COUNT)`.
         // The only exception is if there are no enemies to target.
         if ev.spell == Spell::Fulgur {
             let mut rng = rand::thread_rng();
-            let k = q_enemies.iter().count();
+            let enemies = targets.ranked(ev);
+            let k = enemies.len();
             if k == 0 {
                 continue;
             }
 
-            let m = FULGUR_COUNT / k;
-            let n = FULGUR_COUNT % k;
+            let count = ev.count(FULGUR_COUNT);
+            let damage = ev.damage(DAMAGE);
+            let m = count / k;
+            let n = count % k;
 
             for _ in 0..m {
-                for transform in q_enemies.iter() {
-                    spawn_lightning(&mut commands, &assets, get_pos(&mut rng, transform));
+                for pos in &enemies {
+                    let transform = get_pos(&mut rng, *pos);
+                    spawn_lightning(&mut commands, &assets, transform, ev.faction, damage);
                 }
             }
 
-            for transform in q_enemies.iter().choose_multiple(&mut rng, n) {
-                spawn_lightning(&mut commands, &assets, get_pos(&mut rng, transform));
+            // The remaining strikes go to the best targets.
+            for pos in enemies.iter().take(n) {
+                let transform = get_pos(&mut rng, *pos);
+                spawn_lightning(&mut commands, &assets, transform, ev.faction, damage);
             }
         }
     }
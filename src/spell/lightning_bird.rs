@@ -8,17 +8,22 @@ use bevy_rapier2d::prelude::*;
 use bevy_trickfilm::animation::AnimationPlayer2D;
 
 use crate::audio::PlaySound;
-use crate::player::Player;
 use crate::utils::{quat_from_vec2, NoRotation};
 use crate::world::camera::YSort;
 use crate::{GameAssets, GameState};
 
-use super::{Spell, SpellCasted};
+use super::{
+    damage::{DamageType, Hitbox, Knockback},
+    projectile::{despawn_projectiles, Projectile},
+    targeting::Targets,
+    Faction, Spell, SpellCasted,
+};
 
 const SPEED: f32 = 300.0;
 const SCALE: f32 = 1.5;
-const MAX_PLAYER_DISTANCE: f32 = 500.0;
+const MAX_CASTER_DISTANCE: f32 = 500.0;
 const DESPAWN_TIME: f32 = 20.0;
+const STRIKE_DAMAGE: f32 = 5.0;
 
 const ATTACK_TIME: f32 = 1.0;
 const STRIKE_INTERVALS: [f32; 10] = [0.0, 0.1, 0.2, 0.3, 0.4, 0.85, 0.9, 1.0, 1.1, 1.2];
@@ -29,44 +34,47 @@ const FLAP_TIME_OFFSET: f32 = 0.4;
 
 #[derive(Component)]
 struct LightningBird {
+    /// The bird turns around when it flies too far away from its caster.
+    caster: Entity,
     attack_timer: Timer,
     flap_timer: Timer,
-    despawn_timer: Timer,
-    disabled: bool,
+    strike_damage: f32,
 }
 
 #[derive(Component)]
-pub struct LightningStrike {
-    pub damage: f32,
-}
+pub struct LightningStrike;
 
 #[derive(Component)]
 struct LightningStrikeSpawnTimer {
     pos: Vec3,
+    faction: Faction,
+    damage: f32,
     timer: Timer,
 }
 
 #[derive(Component)]
 struct LightningBirdDeath;
 
-impl Default for LightningBird {
-    fn default() -> Self {
+impl LightningBird {
+    fn new(caster: Entity, strike_damage: f32) -> Self {
         Self {
+            caster,
             attack_timer: Timer::from_seconds(ATTACK_TIME, TimerMode::Once),
             flap_timer: Timer::from_seconds(FLAP_TIME_OFFSET, TimerMode::Once),
-            despawn_timer: Timer::from_seconds(DESPAWN_TIME, TimerMode::Once),
-            disabled: false,
+            strike_damage,
         }
     }
 }
 
-impl Default for LightningStrike {
-    fn default() -> Self {
-        Self { damage: 5.0 }
-    }
-}
-
-fn spawn_lightning_bird(commands: &mut Commands, assets: &Res<GameAssets>, transform: Transform) {
+fn spawn_lightning_bird(
+    commands: &mut Commands,
+    assets: &Res<GameAssets>,
+    transform: Transform,
+    caster: Entity,
+    faction: Faction,
+    accuracy: f32,
+    strike_damage: f32,
+) {
     let mut animation_player = AnimationPlayer2D::default();
     animation_player
         .play(assets.lightning_bird_animations[1].clone())
@@ -84,7 +92,14 @@ fn spawn_lightning_bird(commands: &mut Commands, assets: &Res<GameAssets>, trans
 
     commands
         .spawn((
-            LightningBird::default(),
+            LightningBird::new(caster, strike_damage),
+            Projectile {
+                speed: SPEED,
+                lifetime: Timer::from_seconds(DESPAWN_TIME * accuracy, TimerMode::Once),
+                pierce: None,
+                ..default()
+            },
+            faction,
             animation_player,
             Sprite::from_atlas_image(
                 assets.lightning_bird_texture.clone(),
@@ -101,22 +116,25 @@ fn spawn_lightning_bird(commands: &mut Commands, assets: &Res<GameAssets>, trans
 fn spawn_lightning_birds(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_player: Query<(&Transform, &Player)>,
+    targets: Targets,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let (player_transform, player) = match q_player.single() {
-        Ok(p) => (p.0, p.1),
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::FulgurAvis {
-            let transform = Transform::from_translation(
-                player_transform.translation + Vec3::new(0.0, 0.0, 10.0),
-            )
-            .with_scale(Vec3::splat(SCALE))
-            .with_rotation(quat_from_vec2(Vec2::new(player.current_direction.x, 0.0)));
-            spawn_lightning_bird(&mut commands, &assets, transform);
+            let dir = targets.direction(ev);
+            let transform = Transform::from_translation(ev.origin + Vec3::new(0.0, 0.0, 10.0))
+                .with_scale(Vec3::splat(SCALE))
+                .with_rotation(quat_from_vec2(dir));
+            let strike_damage = ev.damage(STRIKE_DAMAGE);
+            spawn_lightning_bird(
+                &mut commands,
+                &assets,
+                transform,
+                ev.caster,
+                ev.faction,
+                ev.accuracy,
+                strike_damage,
+            );
         }
     }
 }
@@ -139,11 +157,11 @@ fn spawn_flap_sounds(
 
 fn spawn_lightning_strike_spawn_timers(
     mut commands: Commands,
-    q_lightning_birds: Query<(&Transform, &LightningBird)>,
+    q_lightning_birds: Query<(&Transform, &Faction, &LightningBird)>,
 ) {
     let mut rng = thread_rng();
 
-    for (transform, lightning_bird) in &q_lightning_birds {
+    for (transform, faction, lightning_bird) in &q_lightning_birds {
         if !lightning_bird.attack_timer.just_finished() {
             continue;
         }
@@ -155,6 +173,8 @@ fn spawn_lightning_strike_spawn_timers(
             let pos = transform.translation + rand_offset + speed_offset;
             commands.spawn(LightningStrikeSpawnTimer {
                 pos,
+                faction: *faction,
+                damage: lightning_bird.strike_damage,
                 timer: Timer::from_seconds(time, TimerMode::Once),
             });
         }
@@ -177,6 +197,12 @@ fn spawn_lightning_strikes(
             .spawn((
                 Collider::ball(25.0),
                 Sensor,
+                Hitbox {
+                    damage: strike.damage,
+                    damage_type: DamageType::Lightning,
+                    knockback: Knockback::None,
+                    faction: strike.faction,
+                },
                 Transform::from_translation(Vec3::new(0.0, -24.0, 0.0)),
             ))
             .id();
@@ -185,7 +211,7 @@ fn spawn_lightning_strikes(
 
         commands
             .spawn((
-                LightningStrike::default(),
+                LightningStrike,
                 animation_player,
                 Sprite::from_atlas_image(
                     assets.lightning_strike_texture.clone(),
@@ -205,11 +231,6 @@ fn tick_timers(time: Res<Time>, mut q_lightning_birds: Query<&mut LightningBird>
     for mut lightning_bird in &mut q_lightning_birds {
         lightning_bird.flap_timer.tick(time.delta());
         lightning_bird.attack_timer.tick(time.delta());
-        lightning_bird.despawn_timer.tick(time.delta());
-
-        if lightning_bird.despawn_timer.just_finished() {
-            lightning_bird.disabled = true;
-        }
 
         if lightning_bird.flap_timer.mode() == TimerMode::Once
             && lightning_bird.flap_timer.just_finished()
@@ -222,29 +243,19 @@ fn tick_timers(time: Res<Time>, mut q_lightning_birds: Query<&mut LightningBird>
     }
 }
 
-fn move_lightning_birds(
-    time: Res<Time>,
-    mut q_lightning_birds: Query<&mut Transform, With<LightningBird>>,
-) {
-    for mut transform in &mut q_lightning_birds {
-        let dir = transform.local_x();
-        transform.translation += dir * SPEED * time.delta_secs();
-    }
-}
-
 fn rotate_lightning_birds(
-    q_player: Query<&Transform, With<Player>>,
-    mut q_lightning_birds: Query<(&mut Transform, &mut LightningBird), Without<Player>>,
+    q_casters: Query<&Transform, Without<LightningBird>>,
+    mut q_lightning_birds: Query<(&mut Transform, &mut LightningBird)>,
 ) {
-    let player_pos = match q_player.single() {
-        Ok(p) => p.translation.truncate(),
-        Err(_) => return,
-    };
-
     for (mut transform, mut lightning_bird) in &mut q_lightning_birds {
-        let dis = player_pos.distance_squared(transform.translation.truncate());
-        if dis > MAX_PLAYER_DISTANCE.powi(2) {
-            transform.rotation = quat_from_vec2(player_pos - transform.translation.truncate());
+        let caster_pos = match q_casters.get(lightning_bird.caster) {
+            Ok(c) => c.translation.truncate(),
+            Err(_) => continue,
+        };
+
+        let dis = caster_pos.distance_squared(transform.translation.truncate());
+        if dis > MAX_CASTER_DISTANCE.powi(2) {
+            transform.rotation = quat_from_vec2(caster_pos - transform.translation.truncate());
             lightning_bird.attack_timer.reset();
         }
     }
@@ -258,13 +269,13 @@ fn adjust_sprite_flip(
     }
 }
 
-fn despawn_lightning_birds(
+fn spawn_lightning_bird_deaths(
     mut commands: Commands,
     assets: Res<GameAssets>,
-    q_lightning_birds: Query<(Entity, &Transform, &LightningBird)>,
+    q_lightning_birds: Query<(&Transform, &Projectile), With<LightningBird>>,
 ) {
-    for (entity, transform, lightning_bird) in &q_lightning_birds {
-        if lightning_bird.disabled {
+    for (transform, projectile) in &q_lightning_birds {
+        if projectile.disabled {
             let mut animator = AnimationPlayer2D::default();
             animator.play(assets.lightning_bird_death_animations[0].clone());
             commands.spawn((
@@ -279,7 +290,6 @@ fn despawn_lightning_birds(
                 ),
                 Transform::from_translation(transform.translation).with_scale(Vec3::splat(3.0)),
             ));
-            commands.entity(entity).despawn();
         }
     }
 }
@@ -318,14 +328,18 @@ impl Plugin for LightningBirdPlugin {
                 spawn_lightning_strike_spawn_timers,
                 spawn_lightning_strikes,
                 tick_timers,
-                move_lightning_birds,
                 rotate_lightning_birds,
                 adjust_sprite_flip,
-                despawn_lightning_birds,
                 despawn_lightning_strikes,
                 despawn_lightning_bird_deaths,
             )
                 .run_if(in_state(GameState::Gaming)),
+        )
+        .add_systems(
+            PostUpdate,
+            spawn_lightning_bird_deaths
+                .before(despawn_projectiles)
+                .run_if(in_state(GameState::Gaming)),
         );
     }
 }
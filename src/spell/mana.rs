@@ -0,0 +1,83 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::GameState;
+
+use super::Spell;
+
+const MANA_REGEN: f32 = 8.0;
+
+#[derive(Component)]
+pub struct Mana {
+    pub mana: f32,
+    pub max_mana: f32,
+}
+
+impl Mana {
+    pub fn new(max_mana: f32) -> Self {
+        Self {
+            mana: max_mana,
+            max_mana,
+        }
+    }
+}
+
+#[derive(Component, Default)]
+pub struct SpellCooldowns {
+    timers: HashMap<Spell, Timer>,
+}
+
+impl SpellCooldowns {
+    pub fn is_on_cooldown(&self, spell: &Spell) -> bool {
+        self.timers
+            .get(spell)
+            .is_some_and(|timer| !timer.finished())
+    }
+
+    pub fn start(&mut self, spell: Spell, cooldown: f32) {
+        if cooldown > 0.0 {
+            self.timers
+                .insert(spell, Timer::from_seconds(cooldown, TimerMode::Once));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpellRejectReason {
+    NoMana,
+    OnCooldown,
+    Locked,
+}
+
+/// A valid incantation was typed but the spell could not be casted.
+#[derive(Event)]
+pub struct SpellRejected {
+    pub spell: Spell,
+    pub reason: SpellRejectReason,
+}
+
+fn regenerate_mana(time: Res<Time>, mut q_manas: Query<&mut Mana>) {
+    for mut mana in &mut q_manas {
+        mana.mana = (mana.mana + MANA_REGEN * time.delta_secs()).min(mana.max_mana);
+    }
+}
+
+fn tick_cooldowns(time: Res<Time>, mut q_cooldowns: Query<&mut SpellCooldowns>) {
+    for mut cooldowns in &mut q_cooldowns {
+        for timer in cooldowns.timers.values_mut() {
+            timer.tick(time.delta());
+        }
+        cooldowns.timers.retain(|_, timer| !timer.finished());
+    }
+}
+
+pub struct ManaPlugin;
+
+impl Plugin for ManaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (regenerate_mana, tick_cooldowns).run_if(in_state(GameState::Gaming)),
+        )
+        .add_event::<SpellRejected>();
+    }
+}
@@ -1,9 +1,17 @@
 pub mod aer_tracto;
+pub mod damage;
 pub mod debug_spell;
 pub mod fireball;
+pub mod grammar;
 pub mod icicle;
+pub mod invulnerable;
 pub mod lightning;
 pub mod lightning_bird;
+pub mod mana;
+pub mod projectile;
+pub mod registry;
+pub mod status;
+pub mod targeting;
 
 mod cast_spell;
 mod death;
@@ -12,12 +20,19 @@ mod kill_player;
 mod phantasma;
 mod speed_boost;
 
-use std::error::Error;
-use std::fmt::Display;
-use std::str::FromStr;
+use serde::Deserialize;
 
 use bevy::prelude::*;
 
+use crate::ui::text_field::TypingMetrics;
+
+use grammar::SpellModifiers;
+use targeting::TargetMode;
+
+const TYPING_BONUS_MIN_WPM: f32 = 30.0;
+const TYPING_BONUS_MAX_WPM: f32 = 90.0;
+const MAX_TYPING_BONUS: f32 = 0.25;
+
 pub struct SpellPlugin;
 
 impl Plugin for SpellPlugin {
@@ -36,11 +51,20 @@ impl Plugin for SpellPlugin {
             cast_spell::CastSpellPlugin,
             debug_spell::DebugSpellPlugin,
         ))
+        .add_plugins((
+            registry::SpellRegistryPlugin,
+            mana::ManaPlugin,
+            targeting::TargetingPlugin,
+            status::StatusPlugin,
+            projectile::ProjectilePlugin,
+            damage::DamagePlugin,
+            invulnerable::InvulnerablePlugin,
+        ))
         .add_event::<SpellCasted>();
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Deserialize)]
 pub enum Spell {
     Fireball,
     IgnisPila,
@@ -55,49 +79,97 @@ pub enum Spell {
     Death,
     Flub,
     KillPlayer,
+    GodMode,
     Debug,
+    /// Changes the default `TargetMode`.
+    Scopus,
 }
 
-#[derive(Debug)]
-pub struct InvalidSpell;
+/// Which side an entity fights on. Spell effects only hit the opposing faction.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Faction {
+    Player,
+    Enemy,
+}
 
-impl Display for InvalidSpell {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Not a valid spell")
+impl Faction {
+    pub fn is_hostile(&self, other: &Faction) -> bool {
+        self != other
     }
 }
 
-impl Error for InvalidSpell {}
-
-impl FromStr for Spell {
-    type Err = InvalidSpell;
+#[derive(Event)]
+pub struct SpellCasted {
+    spell: Spell,
+    caster: Entity,
+    faction: Faction,
+    /// Where the spell effects spawn, usually the caster's position.
+    origin: Vec3,
+    /// The direction the caster is looking at.
+    facing: Vec2,
+    /// How well the incantation was typed, `1.0` being a perfect match.
+    accuracy: f32,
+    modifiers: SpellModifiers,
+    typing: TypingMetrics,
+    /// `None` uses the mode from `TargetingSettings`.
+    target: Option<TargetMode>,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let spell_str: &str = &s.trim_start().trim_end().to_lowercase();
-        if spell_str.is_empty() {
-            return Err(InvalidSpell);
+impl SpellCasted {
+    pub fn new(spell: Spell, caster: Entity, faction: Faction, origin: Vec3, facing: Vec2) -> Self {
+        Self {
+            spell,
+            caster,
+            faction,
+            origin,
+            facing,
+            accuracy: 1.0,
+            modifiers: SpellModifiers::default(),
+            typing: TypingMetrics::default(),
+            target: None,
         }
+    }
 
-        match spell_str {
-            "fireball" => Ok(Spell::Fireball),
-            "ignis pila" => Ok(Spell::IgnisPila),
-            "inferno pila" => Ok(Spell::InfernoPila),
-            "fulgur" => Ok(Spell::Fulgur),
-            "fulgur avis" => Ok(Spell::FulgurAvis),
-            "scutum glaciei" => Ok(Spell::ScutumGlaciei),
-            "aer tracto" => Ok(Spell::AerTracto),
-            "aer pello" => Ok(Spell::AerPello),
-            "cito" => Ok(Spell::SpeedBoost),
-            "phantasma" => Ok(Spell::Phantasma),
-            "now you" | "jetzt du" => Ok(Spell::Death),
-            "kill player" => Ok(Spell::KillPlayer),
-            "debug" => Ok(Spell::Debug),
-            _ => Ok(Spell::Flub),
-        }
+    fn with_accuracy(mut self, accuracy: f32) -> Self {
+        self.accuracy = accuracy;
+        self
     }
-}
 
-#[derive(Event)]
-pub struct SpellCasted {
-    spell: Spell,
+    fn with_modifiers(mut self, modifiers: SpellModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    fn with_typing(mut self, typing: TypingMetrics) -> Self {
+        self.typing = typing;
+        self
+    }
+
+    pub fn with_target(mut self, target: Option<TargetMode>) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Scale the number of spawned projectiles, but always spawn at least one.
+    fn count(&self, count: usize) -> usize {
+        ((count as f32 * self.accuracy * self.modifiers.count).round() as usize).max(1)
+    }
+
+    fn damage(&self, damage: f32) -> f32 {
+        damage * self.accuracy * self.modifiers.damage * self.typing_bonus()
+    }
+
+    fn radius(&self, radius: f32) -> f32 {
+        radius * self.modifiers.radius * self.typing_bonus()
+    }
+
+    /// Fast and clean typing makes spells stronger.
+    fn typing_bonus(&self) -> f32 {
+        if self.typing.assisted {
+            return 1.0;
+        }
+        let speed = (self.typing.wpm - TYPING_BONUS_MIN_WPM)
+            / (TYPING_BONUS_MAX_WPM - TYPING_BONUS_MIN_WPM);
+        1.0 + MAX_TYPING_BONUS * speed.clamp(0.0, 1.0) * self.typing.accuracy
+    }
 }
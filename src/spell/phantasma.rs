@@ -1,41 +1,43 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::GameState;
-use crate::{player::Player, utils::COLLISION_GROUPS_NONE};
+use crate::{utils::COLLISION_GROUPS_NONE, GameState};
 
-use super::{Spell, SpellCasted};
+use super::{invulnerable::Invulnerable, Spell, SpellCasted};
 
+const TIME: f32 = 30.0;
 const DEFAULT_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 1.0);
 const PHANTASMA_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.5);
 const DEFAULT_COLLISION_GROUPS: CollisionGroups = CollisionGroups::new(Group::ALL, Group::ALL);
 
 #[derive(Component)]
 struct PhantasmaTimer {
+    caster: Entity,
     timer: Timer,
 }
 
-impl Default for PhantasmaTimer {
-    fn default() -> Self {
+impl PhantasmaTimer {
+    fn new(caster: Entity, accuracy: f32) -> Self {
         Self {
-            timer: Timer::from_seconds(30.0, TimerMode::Once),
+            caster,
+            timer: Timer::from_seconds(TIME * accuracy, TimerMode::Once),
         }
     }
 }
 
 fn activate_phantasma(
     mut commands: Commands,
-    mut q_player: Query<(&Children, &mut Sprite), With<Player>>,
+    mut q_casters: Query<(&Children, &mut Sprite, Option<&Invulnerable>)>,
     mut q_colliders: Query<&mut CollisionGroups>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let (children, mut sprite) = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::Phantasma {
+            let (children, mut sprite, invulnerable) = match q_casters.get_mut(ev.caster) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+
             sprite.color = PHANTASMA_COLOR;
 
             for child in children {
@@ -45,7 +47,14 @@ fn activate_phantasma(
                 };
             }
 
-            commands.spawn(PhantasmaTimer::default());
+            // Don't replace god mode.
+            if !invulnerable.is_some_and(|i| i.is_permanent()) {
+                commands
+                    .entity(ev.caster)
+                    .insert(Invulnerable::new(TIME * ev.accuracy).without_blink());
+            }
+
+            commands.spawn(PhantasmaTimer::new(ev.caster, ev.accuracy));
         };
     }
 }
@@ -53,19 +62,19 @@ fn activate_phantasma(
 fn deactivate_phantasma(
     mut commands: Commands,
     time: Res<Time>,
-    mut q_player: Query<(&Children, &mut Sprite), With<Player>>,
+    mut q_casters: Query<(&Children, &mut Sprite)>,
     mut q_colliders: Query<&mut CollisionGroups>,
     mut q_timers: Query<(Entity, &mut PhantasmaTimer)>,
 ) {
-    let (children, mut sprite) = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
     for (entity, mut timer) in &mut q_timers {
         timer.timer.tick(time.delta());
 
         if timer.timer.just_finished() {
+            let Ok((children, mut sprite)) = q_casters.get_mut(timer.caster) else {
+                commands.entity(entity).despawn();
+                continue;
+            };
+
             sprite.color = DEFAULT_COLOR;
 
             for child in children {
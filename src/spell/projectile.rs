@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::GameState;
+
+use super::damage::{resolve_hits, Hitbox};
+
+const SCALE_IN_TIME: f32 = 0.35;
+
+/// Steers the projectile towards `target`.
+pub struct Homing {
+    pub target: Entity,
+    /// Radians per second.
+    pub turn_rate: f32,
+}
+
+/// Flies along its local x axis until `lifetime` runs out or it can't pierce anymore.
+/// Damage is dealt through a `Hitbox`, see `resolve_hits`.
+/// Hits that count are also reported through `ProjectileHit`.
+#[derive(Component)]
+pub struct Projectile {
+    pub speed: f32,
+    /// Added to `speed` every second.
+    pub acceleration: f32,
+    pub lifetime: Timer,
+    /// How many entities the projectile passes through, `None` pierces everything.
+    pub pierce: Option<usize>,
+    pub homing: Option<Homing>,
+    /// Spawn the projectile with zero scale and grow it to this scale.
+    pub scale_in: Option<f32>,
+    /// Blockable projectiles get destroyed by hostile `ProjectileBlocker`s.
+    pub blockable: bool,
+    /// Disabled projectiles get despawned at the end of the frame.
+    pub disabled: bool,
+}
+
+/// A projectile's `Hitbox` hit a hostile `Hurtbox`, `target` is the owner of the `Hurtbox`.
+#[derive(Event)]
+pub struct ProjectileHit {
+    pub projectile: Entity,
+    pub target: Entity,
+}
+
+/// Put on a collider with a `Hitbox` to destroy hostile, blockable projectiles, e.g. icicles.
+#[derive(Component)]
+pub struct ProjectileBlocker;
+
+impl Default for Projectile {
+    fn default() -> Self {
+        Self {
+            speed: 0.0,
+            acceleration: 0.0,
+            lifetime: Timer::from_seconds(5.0, TimerMode::Once),
+            pierce: Some(0),
+            homing: None,
+            scale_in: None,
+            blockable: false,
+            disabled: false,
+        }
+    }
+}
+
+impl Projectile {
+    /// Returns whether the hit counts, i.e. the projectile wasn't used up yet.
+    pub fn hit(&mut self) -> bool {
+        if self.disabled {
+            return false;
+        }
+
+        match self.pierce {
+            Some(0) => self.disabled = true,
+            Some(n) => self.pierce = Some(n - 1),
+            None => {}
+        }
+        true
+    }
+}
+
+fn steer_projectiles(
+    time: Res<Time>,
+    q_targets: Query<&Transform, Without<Projectile>>,
+    mut q_projectiles: Query<(&mut Transform, &Projectile)>,
+) {
+    for (mut transform, projectile) in &mut q_projectiles {
+        let homing = match &projectile.homing {
+            Some(h) => h,
+            None => continue,
+        };
+        let target_pos = match q_targets.get(homing.target) {
+            Ok(t) => t.translation.truncate(),
+            Err(_) => continue,
+        };
+
+        let dir = transform.local_x().truncate();
+        let angle = dir.angle_to(target_pos - transform.translation.truncate());
+        let max_angle = homing.turn_rate * time.delta_secs();
+        transform.rotate_z(angle.clamp(-max_angle, max_angle));
+    }
+}
+
+fn move_projectiles(time: Res<Time>, mut q_projectiles: Query<(&mut Transform, &mut Projectile)>) {
+    for (mut transform, mut projectile) in &mut q_projectiles {
+        projectile.speed += projectile.acceleration * time.delta_secs();
+        let dir = transform.local_x();
+        transform.translation += dir * projectile.speed * time.delta_secs();
+    }
+}
+
+fn scale_projectiles(time: Res<Time>, mut q_projectiles: Query<(&mut Transform, &Projectile)>) {
+    for (mut transform, projectile) in &mut q_projectiles {
+        let scale = match projectile.scale_in {
+            Some(s) => s,
+            None => continue,
+        };
+
+        if transform.scale.x < scale {
+            transform.scale += Vec3::ONE * scale / SCALE_IN_TIME * time.delta_secs();
+        }
+    }
+}
+
+fn tick_lifetimes(time: Res<Time>, mut q_projectiles: Query<&mut Projectile>) {
+    for mut projectile in &mut q_projectiles {
+        projectile.lifetime.tick(time.delta());
+        if projectile.lifetime.just_finished() {
+            projectile.disabled = true;
+        }
+    }
+}
+
+fn block_projectiles(
+    q_blockers: Query<&Hitbox, With<ProjectileBlocker>>,
+    q_hitboxes: Query<(&Hitbox, &ChildOf)>,
+    mut q_projectiles: Query<&mut Projectile>,
+    mut ev_collision_events: EventReader<CollisionEvent>,
+) {
+    for ev in ev_collision_events.read() {
+        let (source, target) = match ev {
+            CollisionEvent::Started(source, target, _) => (*source, *target),
+            CollisionEvent::Stopped(_, _, _) => continue,
+        };
+
+        for (blocker_entity, projectile_entity) in [(source, target), (target, source)] {
+            let blocker = match q_blockers.get(blocker_entity) {
+                Ok(b) => b,
+                Err(_) => continue,
+            };
+            let (hitbox, projectile_parent) = match q_hitboxes.get(projectile_entity) {
+                Ok(h) => h,
+                Err(_) => continue,
+            };
+            let mut projectile = match q_projectiles.get_mut(projectile_parent.parent()) {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+
+            if projectile.blockable && blocker.faction.is_hostile(&hitbox.faction) {
+                projectile.disabled = true;
+            }
+        }
+    }
+}
+
+pub fn despawn_projectiles(mut commands: Commands, q_projectiles: Query<(Entity, &Projectile)>) {
+    for (entity, projectile) in &q_projectiles {
+        if projectile.disabled {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                steer_projectiles,
+                move_projectiles.after(steer_projectiles),
+                scale_projectiles,
+                tick_lifetimes,
+                block_projectiles.before(resolve_hits),
+            )
+                .run_if(in_state(GameState::Gaming)),
+        )
+        // Despawn late so that every system can still look at the projectiles that hit something.
+        .add_systems(
+            PostUpdate,
+            despawn_projectiles.run_if(in_state(GameState::Gaming)),
+        )
+        .add_event::<ProjectileHit>();
+    }
+}
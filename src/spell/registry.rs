@@ -0,0 +1,203 @@
+use serde::Deserialize;
+
+use bevy::asset::LoadContext;
+use bevy::prelude::*;
+
+use crate::item::Item;
+use crate::utils::ron_asset::{RonAsset, RonAssetLoader};
+use crate::GameAssets;
+
+use super::{grammar::normalize, Spell};
+
+/// When a spell can be casted outside of debug mode.
+#[derive(Deserialize, Clone, PartialEq)]
+pub enum SpellUnlock {
+    Always,
+    Item(Item),
+    DebugOnly,
+}
+
+#[derive(Deserialize)]
+pub struct SpellEntryDef {
+    spell: Spell,
+    incantations: Vec<String>,
+    unlock: SpellUnlock,
+    title: String,
+    description: String,
+    icon: Option<String>,
+    #[serde(default)]
+    exact: bool,
+    #[serde(default)]
+    mana_cost: f32,
+    #[serde(default)]
+    cooldown: f32,
+}
+
+#[derive(Clone)]
+pub struct SpellEntry {
+    pub spell: Spell,
+    pub incantations: Vec<String>,
+    pub unlock: SpellUnlock,
+    pub title: String,
+    pub description: String,
+    pub icon: Option<Handle<Image>>,
+    /// Only cast when typed exactly, never as a weakened version.
+    pub exact: bool,
+    pub mana_cost: f32,
+    /// Seconds until the spell can be casted again.
+    pub cooldown: f32,
+}
+
+/// All castable spells, loaded from `assets/spell/registry.spells.ron`.
+/// Adding an alias or renaming a spell only requires editing that file.
+#[derive(Asset, Resource, TypePath, Clone)]
+pub struct SpellRegistry {
+    entries: Vec<SpellEntry>,
+}
+
+/// How forgiving the incantation matching is.
+/// Casts with an accuracy below `threshold` flub.
+#[derive(Resource)]
+pub struct SpellMatching {
+    pub threshold: f32,
+}
+
+impl Default for SpellMatching {
+    fn default() -> Self {
+        Self { threshold: 0.75 }
+    }
+}
+
+impl SpellRegistry {
+    /// Resolve the typed incantation to the closest spell and the accuracy of the match,
+    /// `1.0` being a perfect match.
+    /// Returns `None` if nothing was typed and `Spell::Flub` if no spell is close enough.
+    pub fn parse(&self, incantation: &str, threshold: f32) -> Option<(Spell, f32)> {
+        let incantation: &str = &normalize(incantation.trim());
+        if incantation.is_empty() {
+            return None;
+        }
+
+        let mut best: Option<(&Spell, f32)> = None;
+        for entry in &self.entries {
+            for s in &entry.incantations {
+                let accuracy = match_accuracy(incantation, s);
+                if entry.exact && accuracy < 1.0 {
+                    continue;
+                }
+                if best.is_none_or(|(_, best_accuracy)| accuracy > best_accuracy) {
+                    best = Some((&entry.spell, accuracy));
+                }
+            }
+        }
+
+        match best {
+            Some((spell, accuracy)) if accuracy >= threshold => Some((spell.clone(), accuracy)),
+            _ => Some((Spell::Flub, 0.0)),
+        }
+    }
+
+    pub fn entries(&self) -> &[SpellEntry] {
+        &self.entries
+    }
+
+    pub fn entry(&self, spell: &Spell) -> Option<&SpellEntry> {
+        self.entries.iter().find(|entry| &entry.spell == spell)
+    }
+
+    pub fn item_entry(&self, item: &Item) -> Option<&SpellEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.unlock == SpellUnlock::Item(item.clone()))
+    }
+
+    pub fn is_unlocked(&self, active_items: &[Item], spell: &Spell) -> bool {
+        if spell == &Spell::Flub {
+            return true;
+        }
+
+        match self.entry(spell).map(|entry| &entry.unlock) {
+            Some(SpellUnlock::Always) => true,
+            Some(SpellUnlock::Item(item)) => active_items.contains(item),
+            Some(SpellUnlock::DebugOnly) | None => false,
+        }
+    }
+}
+
+/// `1.0 - levenshtein_distance / max_len`, so `0.0` means nothing in common.
+fn match_accuracy(typed: &str, incantation: &str) -> f32 {
+    let a: Vec<char> = typed.chars().collect();
+    let b: Vec<char> = incantation.chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev_diagonal = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let value = (row[j] + 1).min(row[j - 1] + 1).min(prev_diagonal + cost);
+            prev_diagonal = row[j];
+            row[j] = value;
+        }
+    }
+    1.0 - row[b.len()] as f32 / max_len as f32
+}
+
+impl FromWorld for SpellRegistry {
+    fn from_world(world: &mut World) -> Self {
+        let handle = world.resource::<GameAssets>().spell_registry.clone();
+        match world.resource::<Assets<SpellRegistry>>().get(&handle) {
+            Some(registry) => registry.clone(),
+            None => {
+                error!("spell registry asset is not loaded, no spell will be castable");
+                SpellRegistry {
+                    entries: Vec::new(),
+                }
+            }
+        }
+    }
+}
+
+impl RonAsset for SpellRegistry {
+    type Def = Vec<SpellEntryDef>;
+
+    fn extensions() -> &'static [&'static str] {
+        &["spells.ron"]
+    }
+
+    fn from_def(defs: Self::Def, load_context: &mut LoadContext<'_>) -> Self {
+        let entries = defs
+            .into_iter()
+            .map(|def| SpellEntry {
+                spell: def.spell,
+                incantations: def
+                    .incantations
+                    .iter()
+                    .map(|s| normalize(s.trim()))
+                    .collect(),
+                unlock: def.unlock,
+                title: def.title,
+                description: def.description,
+                icon: def.icon.map(|path| load_context.load(path)),
+                exact: def.exact,
+                mana_cost: def.mana_cost,
+                cooldown: def.cooldown,
+            })
+            .collect();
+        SpellRegistry { entries }
+    }
+}
+
+pub struct SpellRegistryPlugin;
+
+impl Plugin for SpellRegistryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SpellRegistry>()
+            .init_asset_loader::<RonAssetLoader<SpellRegistry>>()
+            .init_resource::<SpellMatching>();
+    }
+}
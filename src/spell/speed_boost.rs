@@ -1,76 +1,40 @@
-use std::time::Duration;
-
 use bevy::prelude::*;
 
-use crate::player::stats::Stats;
-use crate::player::Player;
+use crate::player::stats::{Stat, StatModifier, StatSource, Stats};
 use crate::GameState;
 
 use super::{Spell, SpellCasted};
 
-#[derive(Component)]
-struct SpeedBoostTimer {
-    timer: Timer,
-}
-
-impl Default for SpeedBoostTimer {
-    fn default() -> Self {
-        Self {
-            timer: Timer::new(Duration::from_secs_f32(30.0), TimerMode::Once),
-        }
-    }
-}
+const TIME: f32 = 30.0;
 
 fn activate_speed_boost(
-    mut commands: Commands,
-    mut q_player: Query<&mut Stats, With<Player>>,
+    mut q_stats: Query<&mut Stats>,
     mut ev_spell_casted: EventReader<SpellCasted>,
 ) {
-    let mut player_stats = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
     for ev in ev_spell_casted.read() {
         if ev.spell == Spell::SpeedBoost {
-            player_stats.move_speed = 5.0 * Stats::default().move_speed;
-            commands.spawn(SpeedBoostTimer::default());
+            let mut stats = match q_stats.get_mut(ev.caster) {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+
+            // Casting it again refreshes the boost instead of stacking it.
+            stats.remove_source(StatSource::SpeedBoost);
+            stats.add_modifier(
+                StatModifier::percent(Stat::MoveSpeed, 4.0 * ev.accuracy, StatSource::SpeedBoost)
+                    .with_duration(TIME),
+            );
         };
     }
 }
 
-fn deactivate_speed_boost(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut q_player: Query<&mut Stats, With<Player>>,
-    mut q_timers: Query<(Entity, &mut SpeedBoostTimer)>,
-) {
-    let mut player_stats = match q_player.single_mut() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-
-    let deactivate = q_timers.iter().count() == 1;
-
-    for (entity, mut timer) in &mut q_timers {
-        timer.timer.tick(time.delta());
-
-        if timer.timer.just_finished() {
-            if deactivate {
-                player_stats.move_speed = Stats::default().move_speed;
-            }
-            commands.entity(entity).despawn();
-        }
-    }
-}
-
 pub struct SpeedBoostPlugin;
 
 impl Plugin for SpeedBoostPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (activate_speed_boost, deactivate_speed_boost).run_if(in_state(GameState::Gaming)),
+            (activate_speed_boost,).run_if(in_state(GameState::Gaming)),
         );
     }
 }
@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use crate::{ui::health::Health, GameState};
+
+use super::damage::{DamageType, Resistances};
+use super::invulnerable::Invulnerable;
+
+const MAX_STACKS: usize = 3;
+
+const BURNING_TIME: f32 = 3.0;
+const BURNING_INTERVAL: f32 = 1.0;
+const BURNING_DAMAGE: f32 = 1.0;
+const FROZEN_TIME: f32 = 2.0;
+/// Movement multiplier per stack of `Frozen`.
+const FROZEN_SLOW: f32 = 0.6;
+const SHOCKED_TIME: f32 = 0.6;
+const KNOCKED_BACK_TIME: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusKind {
+    /// Damage over time.
+    Burning,
+    /// Slower movement.
+    Frozen,
+    /// Can't change state.
+    Shocked,
+    /// Movement is not controlled by the entity itself.
+    KnockedBack,
+}
+
+impl StatusKind {
+    fn duration(&self) -> f32 {
+        match self {
+            StatusKind::Burning => BURNING_TIME,
+            StatusKind::Frozen => FROZEN_TIME,
+            StatusKind::Shocked => SHOCKED_TIME,
+            StatusKind::KnockedBack => KNOCKED_BACK_TIME,
+        }
+    }
+
+    fn tint(&self) -> Color {
+        match self {
+            StatusKind::Burning => Color::srgb(1.0, 0.55, 0.4),
+            StatusKind::Frozen => Color::srgb(0.55, 0.8, 1.0),
+            StatusKind::Shocked => Color::srgb(1.0, 1.0, 0.45),
+            StatusKind::KnockedBack => Color::srgb(0.8, 0.8, 0.8),
+        }
+    }
+}
+
+impl DamageType {
+    /// The status effect a hit of this type inflicts.
+    fn status_kind(&self) -> Option<StatusKind> {
+        match self {
+            DamageType::Fire => Some(StatusKind::Burning),
+            DamageType::Lightning => Some(StatusKind::Shocked),
+            DamageType::Ice => Some(StatusKind::Frozen),
+            DamageType::Air => Some(StatusKind::KnockedBack),
+            DamageType::Physical | DamageType::Void => None,
+        }
+    }
+}
+
+struct StatusEffect {
+    kind: StatusKind,
+    timer: Timer,
+}
+
+/// Timed effects on an entity with `Health`.
+/// Applying the same effect multiple times stacks it, up to `MAX_STACKS`.
+#[derive(Component)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+    burning_timer: Timer,
+    tinted: bool,
+}
+
+impl Default for StatusEffects {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+            burning_timer: Timer::from_seconds(BURNING_INTERVAL, TimerMode::Repeating),
+            tinted: false,
+        }
+    }
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, kind: StatusKind) {
+        let timer = Timer::from_seconds(kind.duration(), TimerMode::Once);
+        if self.stacks(kind) < MAX_STACKS {
+            self.effects.push(StatusEffect { kind, timer });
+            return;
+        }
+
+        // Refresh the oldest stack instead.
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            effect.timer = timer;
+        }
+    }
+
+    /// Apply the effect that belongs to the type of the hit, if any.
+    pub fn inflict(&mut self, damage_type: DamageType) {
+        if let Some(kind) = damage_type.status_kind() {
+            self.apply(kind);
+        }
+    }
+
+    pub fn stacks(&self, kind: StatusKind) -> usize {
+        self.effects.iter().filter(|e| e.kind == kind).count()
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.stacks(kind) > 0
+    }
+
+    /// Movement multiplier, `1.0` if the entity isn't frozen.
+    pub fn slow(&self) -> f32 {
+        FROZEN_SLOW.powi(self.stacks(StatusKind::Frozen) as i32)
+    }
+
+    pub fn tint(&self) -> Option<Color> {
+        self.effects.last().map(|e| e.kind.tint())
+    }
+}
+
+fn tick_status_effects(time: Res<Time>, mut q_status_effects: Query<&mut StatusEffects>) {
+    for mut status_effects in &mut q_status_effects {
+        for effect in &mut status_effects.effects {
+            effect.timer.tick(time.delta());
+        }
+        status_effects.effects.retain(|e| !e.timer.finished());
+    }
+}
+
+fn burn(
+    time: Res<Time>,
+    mut q_healths: Query<(
+        &mut Health,
+        &mut StatusEffects,
+        Option<&Resistances>,
+        Has<Invulnerable>,
+    )>,
+) {
+    for (mut health, mut status_effects, resistances, invulnerable) in &mut q_healths {
+        let stacks = status_effects.stacks(StatusKind::Burning);
+        if stacks == 0 {
+            status_effects.burning_timer.reset();
+            continue;
+        }
+
+        status_effects.burning_timer.tick(time.delta());
+        if status_effects.burning_timer.just_finished() && !invulnerable {
+            let damage = BURNING_DAMAGE * stacks as f32;
+            health.damage(resistances.map_or(damage, |r| r.apply(damage, DamageType::Fire)));
+        }
+    }
+}
+
+pub fn tint_sprites(mut q_status_effects: Query<(&mut StatusEffects, &mut Sprite)>) {
+    for (mut status_effects, mut sprite) in &mut q_status_effects {
+        let tint = status_effects.tint();
+        if tint.is_none() && !status_effects.tinted {
+            continue;
+        }
+
+        status_effects.tinted = tint.is_some();
+        // Keep the alpha, it's used by other effects like phantasma.
+        let alpha = sprite.color.alpha();
+        sprite.color = tint.unwrap_or(Color::WHITE).with_alpha(alpha);
+    }
+}
+
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (tick_status_effects, burn, tint_sprites).run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::{player::input::MouseWorldCoords, ui::health::Health};
+
+use super::{Faction, Spell, SpellCasted};
+
+/// Which hostile entity a directional spell aims at.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TargetMode {
+    #[default]
+    Nearest,
+    Cursor,
+    LowestHealth,
+    Facing,
+}
+
+impl TargetMode {
+    pub const ALL: [TargetMode; 4] = [
+        TargetMode::Nearest,
+        TargetMode::Cursor,
+        TargetMode::LowestHealth,
+        TargetMode::Facing,
+    ];
+
+    /// The incantation suffix that selects this mode, e.g. `fireball debilis`.
+    pub fn word(&self) -> &'static str {
+        match self {
+            TargetMode::Nearest => "proxima",
+            TargetMode::Cursor => "cursor",
+            TargetMode::LowestHealth => "debilis",
+            TargetMode::Facing => "ante",
+        }
+    }
+
+    pub fn from_word(word: &str) -> Option<Self> {
+        TargetMode::ALL.into_iter().find(|m| m.word() == word)
+    }
+
+    fn next(&self) -> Self {
+        let index = TargetMode::ALL.iter().position(|m| m == self).unwrap_or(0);
+        TargetMode::ALL[(index + 1) % TargetMode::ALL.len()]
+    }
+}
+
+/// The target mode used when the incantation has no suffix.
+/// Changed with `Spell::Scopus`.
+#[derive(Resource, Default)]
+pub struct TargetingSettings {
+    pub mode: TargetMode,
+}
+
+/// Picks targets for spells, see `TargetMode`.
+#[derive(SystemParam)]
+pub struct Targets<'w, 's> {
+    settings: Res<'w, TargetingSettings>,
+    mouse_coords: Res<'w, MouseWorldCoords>,
+    q_targets: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Transform,
+            &'static Faction,
+            Option<&'static Health>,
+        ),
+    >,
+}
+
+impl Targets<'_, '_> {
+    /// The settings only apply to the player, other casters default to `TargetMode::Nearest`.
+    fn mode(&self, ev: &SpellCasted) -> TargetMode {
+        match (ev.target, ev.faction) {
+            (Some(mode), _) => mode,
+            (None, Faction::Player) => self.settings.mode,
+            (None, Faction::Enemy) => TargetMode::Nearest,
+        }
+    }
+
+    /// Everything hostile to the caster, the best target first.
+    fn ranked_entities(&self, ev: &SpellCasted) -> Vec<(Entity, Vec3)> {
+        let mode = self.mode(ev);
+        let cursor = self.mouse_coords.0;
+        let origin = ev.origin.truncate();
+        let facing = ev.facing.normalize_or(Vec2::X);
+
+        let mut enemies: Vec<(Entity, Vec3, f32)> = self
+            .q_targets
+            .iter()
+            .filter(|(_, _, faction, _)| ev.faction.is_hostile(faction))
+            .map(|(entity, transform, _, health)| {
+                let pos = transform.translation.truncate();
+                let score = match mode {
+                    TargetMode::Nearest => pos.distance_squared(origin),
+                    TargetMode::Cursor => pos.distance_squared(cursor),
+                    TargetMode::LowestHealth => health.map_or(f32::MAX, |h| h.health),
+                    TargetMode::Facing => facing.angle_to(pos - origin).abs(),
+                };
+                (entity, transform.translation, score)
+            })
+            .collect();
+        enemies.sort_by(|a, b| {
+            a.2.partial_cmp(&b.2)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| {
+                    let a_dis = a.1.truncate().distance_squared(origin);
+                    let b_dis = b.1.truncate().distance_squared(origin);
+                    a_dis.partial_cmp(&b_dis).unwrap_or(Ordering::Equal)
+                })
+        });
+        enemies
+            .into_iter()
+            .map(|(entity, pos, _)| (entity, pos))
+            .collect()
+    }
+
+    /// Positions of everything hostile to the caster, the best target first.
+    pub fn ranked(&self, ev: &SpellCasted) -> Vec<Vec3> {
+        self.ranked_entities(ev)
+            .into_iter()
+            .map(|(_, pos)| pos)
+            .collect()
+    }
+
+    /// The entity a homing spell should follow.
+    /// `None` when aiming at the cursor or straight ahead.
+    pub fn target(&self, ev: &SpellCasted) -> Option<Entity> {
+        match self.mode(ev) {
+            TargetMode::Cursor | TargetMode::Facing => None,
+            _ => self.ranked_entities(ev).first().map(|(entity, _)| *entity),
+        }
+    }
+
+    /// Normalized direction a projectile fired from the spell's origin should fly in.
+    /// Falls back to the caster's facing if there is nothing to aim at.
+    pub fn direction(&self, ev: &SpellCasted) -> Vec2 {
+        let origin = ev.origin.truncate();
+        let facing = ev.facing.normalize_or(Vec2::X);
+        let target = match self.mode(ev) {
+            TargetMode::Cursor => Some(self.mouse_coords.0),
+            TargetMode::Facing => None,
+            _ => self.ranked(ev).first().map(|pos| pos.truncate()),
+        };
+
+        match target {
+            Some(target) => (target - origin).normalize_or(facing),
+            None => facing,
+        }
+    }
+}
+
+fn change_target_mode(
+    mut settings: ResMut<TargetingSettings>,
+    mut ev_spell_casted: EventReader<SpellCasted>,
+) {
+    for ev in ev_spell_casted.read() {
+        if ev.spell == Spell::Scopus && ev.faction == Faction::Player {
+            settings.mode = ev.target.unwrap_or(settings.mode.next());
+        }
+    }
+}
+
+pub struct TargetingPlugin;
+
+impl Plugin for TargetingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TargetingSettings>()
+            .add_systems(Update, change_target_mode);
+    }
+}
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use crate::{
     audio::{GameAudio, PlaySound},
     player::speed_timer::SpeedTimer,
+    ui::text_field::TypingSession,
     GameAssets, GameState,
 };
 
@@ -59,9 +60,16 @@ fn spawn_time(commands: &mut Commands, font: Handle<Font>, time: f32) -> Entity
         .id()
 }
 
-fn spawn_text(commands: &mut Commands, font: Handle<Font>, time: f32) {
+fn spawn_typing(commands: &mut Commands, font: Handle<Font>, session: &TypingSession) -> Entity {
+    commands
+        .spawn((GameOverScreen, session.summary_text(font)))
+        .id()
+}
+
+fn spawn_text(commands: &mut Commands, font: Handle<Font>, time: f32, session: &TypingSession) {
     let title_text = spawn_title(commands, font.clone());
     let time_text = spawn_time(commands, font.clone(), time);
+    let typing_text = spawn_typing(commands, font, session);
 
     commands
         .spawn((
@@ -77,7 +85,7 @@ fn spawn_text(commands: &mut Commands, font: Handle<Font>, time: f32) {
             },
             ZIndex(101),
         ))
-        .add_children(&[title_text, time_text]);
+        .add_children(&[title_text, time_text, typing_text]);
 }
 
 fn spawn_audio_silence_timer(commands: &mut Commands) {
@@ -91,9 +99,15 @@ fn spawn_game_over_screen(
     mut commands: Commands,
     assets: Res<GameAssets>,
     speed_timer: Res<SpeedTimer>,
+    typing_session: Res<TypingSession>,
 ) {
     spawn_background(&mut commands, assets.white_pixel.clone());
-    spawn_text(&mut commands, assets.font.clone(), speed_timer.elapsed);
+    spawn_text(
+        &mut commands,
+        assets.font.clone(),
+        speed_timer.elapsed,
+        &typing_session,
+    );
     spawn_audio_silence_timer(&mut commands);
 }
 
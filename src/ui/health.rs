@@ -1,18 +1,30 @@
 use bevy::prelude::*;
 
 use crate::{
-    item::platform::TriggerFinalAct,
-    player::{Player, PLAYER_HEALTH},
-    world::CameraShake,
-    GameAssets, GameState,
+    item::platform::TriggerFinalAct, player::Player, world::CameraShake, GameAssets, GameState,
 };
 
 use super::world_text::{SpawnWorldText, WorldText};
 
+const DAMAGE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.0);
+const HEAL_COLOR: Color = Color::srgba(0.4, 1.0, 0.4, 0.0);
+const ABSORB_COLOR: Color = Color::srgba(0.5, 0.8, 1.0, 0.0);
+const OVERHEAL_HEART_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+const SHIELD_HEART_COLOR: Color = Color::srgb(0.5, 0.8, 1.0);
+
 #[derive(Component, Clone)]
 pub struct Health {
     pub health: f32,
+    pub max_health: f32,
+    /// Absorbs damage before `health` does, see `add_shield`.
+    pub shield: f32,
+    /// Flat reduction of every hit.
+    pub armor: f32,
+    /// How far healing can go above `max_health`.
+    pub overheal: f32,
+    shield_timer: Timer,
     old_health: f32,
+    absorbed: f32,
 }
 
 #[derive(Component)]
@@ -28,19 +40,69 @@ pub struct SpawnPlayerHearts {
     pub count: usize,
 }
 
+#[derive(PartialEq)]
+enum HealthChangeKind {
+    Damage,
+    Heal,
+    /// Damage that was taken by the shield.
+    Absorb,
+}
+
 #[derive(Event)]
 struct HealthChanged {
     entity: Entity,
-    health_change: f32,
+    amount: f32,
+    kind: HealthChangeKind,
 }
 
 impl Health {
     pub fn new(max_health: f32) -> Self {
         Self {
             health: max_health,
+            max_health,
+            shield: 0.0,
+            armor: 0.0,
+            overheal: 0.0,
+            shield_timer: Timer::default(),
             old_health: max_health,
+            absorbed: 0.0,
+        }
+    }
+
+    pub fn with_armor(mut self, armor: f32) -> Self {
+        self.armor = armor;
+        self
+    }
+
+    pub fn with_overheal(mut self, overheal: f32) -> Self {
+        self.overheal = overheal;
+        self
+    }
+
+    /// Armor reduces the damage first, what's left goes to the shield and then to `health`.
+    /// Returns the health that was lost, i.e. without what armor and shields took.
+    pub fn damage(&mut self, damage: f32) -> f32 {
+        let damage = (damage - self.armor).max(0.0);
+        let absorbed = damage.min(self.shield);
+        let lost = (damage - absorbed).min(self.health.max(0.0));
+        self.shield -= absorbed;
+        self.absorbed += absorbed;
+        self.health -= damage - absorbed;
+        lost
+    }
+
+    pub fn heal(&mut self, amount: f32) {
+        let max = self.max_health + self.overheal;
+        if self.health < max {
+            self.health = (self.health + amount).min(max);
         }
     }
+
+    /// Shields don't stack, the bigger one is kept and lasts for `duration` seconds.
+    pub fn add_shield(&mut self, shield: f32, duration: f32) {
+        self.shield = self.shield.max(shield);
+        self.shield_timer = Timer::from_seconds(duration, TimerMode::Once);
+    }
 }
 
 fn spawn_health_damage_text(
@@ -54,10 +116,18 @@ fn spawn_health_damage_text(
             Err(_) => continue,
         };
 
+        let font_color = match ev.kind {
+            HealthChangeKind::Damage => DAMAGE_COLOR,
+            HealthChangeKind::Heal => HEAL_COLOR,
+            HealthChangeKind::Absorb => ABSORB_COLOR,
+        };
         ev_spawn_damage_text.write(SpawnWorldText {
-            world_text: WorldText::default(),
+            world_text: WorldText {
+                font_color,
+                ..default()
+            },
             pos,
-            content: ev.health_change.to_string(),
+            content: ev.amount.to_string(),
         });
     }
 }
@@ -67,25 +137,72 @@ fn check_health_changed(
     mut ev_health_changed: EventWriter<HealthChanged>,
 ) {
     for (entity, mut health) in &mut q_healths {
+        if health.absorbed > 0.0 {
+            ev_health_changed.write(HealthChanged {
+                entity,
+                amount: health.absorbed,
+                kind: HealthChangeKind::Absorb,
+            });
+            health.absorbed = 0.0;
+        }
+
         if health.health != health.old_health {
-            let health_change = (health.health - health.old_health).abs();
+            let kind = if health.health < health.old_health {
+                HealthChangeKind::Damage
+            } else {
+                HealthChangeKind::Heal
+            };
             ev_health_changed.write(HealthChanged {
                 entity,
-                health_change,
+                amount: (health.health - health.old_health).abs(),
+                kind,
             });
             health.old_health = health.health;
         }
     }
 }
 
-fn spawn_heart(commands: &mut Commands, assets: &Res<GameAssets>, index: usize) -> Entity {
+fn expire_shields(time: Res<Time>, mut q_healths: Query<&mut Health>) {
+    for mut health in &mut q_healths {
+        if health.shield <= 0.0 {
+            continue;
+        }
+
+        health.shield_timer.tick(time.delta());
+        if health.shield_timer.just_finished() {
+            health.shield = 0.0;
+        }
+    }
+}
+
+/// Full hearts for `health`, empty ones up to `max_health` and the shield on top of that.
+fn heart_image(assets: &Res<GameAssets>, health: &Health, index: usize) -> ImageNode {
+    let full_hearts = health.health.max(0.0) as usize;
+    let hearts = health.health.max(health.max_health).ceil() as usize;
+
+    if index < full_hearts {
+        let color = if index >= health.max_health as usize {
+            OVERHEAL_HEART_COLOR
+        } else {
+            Color::WHITE
+        };
+        ImageNode::new(assets.heart_full.clone()).with_color(color)
+    } else if index < hearts {
+        ImageNode::new(assets.heart_empty.clone())
+    } else {
+        ImageNode::new(assets.heart_full.clone()).with_color(SHIELD_HEART_COLOR)
+    }
+}
+
+fn heart_count(health: &Health) -> usize {
+    health.health.max(health.max_health).ceil() as usize + health.shield.ceil() as usize
+}
+
+fn spawn_heart(commands: &mut Commands, image: ImageNode, index: usize) -> Entity {
     commands
         .spawn((
             Heart { index },
-            ImageNode {
-                image: assets.heart_full.clone(),
-                ..default()
-            },
+            image,
             Node {
                 width: Val::Percent(4.0),
                 ..default()
@@ -97,6 +214,7 @@ fn spawn_heart(commands: &mut Commands, assets: &Res<GameAssets>, index: usize)
 fn spawn_hearts(
     mut commands: Commands,
     assets: Res<GameAssets>,
+    q_player: Query<&Health, With<Player>>,
     mut ev_spawn_player_hearts: EventReader<SpawnPlayerHearts>,
 ) {
     for ev in ev_spawn_player_hearts.read() {
@@ -113,7 +231,11 @@ fn spawn_hearts(
             .id();
 
         for i in 0..ev.count {
-            let heart_entity = spawn_heart(&mut commands, &assets, i);
+            let image = match q_player.single() {
+                Ok(health) => heart_image(&assets, health, i),
+                Err(_) => ImageNode::new(assets.heart_full.clone()),
+            };
+            let heart_entity = spawn_heart(&mut commands, image, i);
             commands.entity(root).add_children(&[heart_entity]);
         }
     }
@@ -129,27 +251,50 @@ fn despawn_hearts(
 }
 
 fn update_player_hearts(
+    mut commands: Commands,
     assets: Res<GameAssets>,
+    q_player: Query<&Health, (With<Player>, Changed<Health>)>,
+    q_hearts_containers: Query<Entity, With<HeartsContainer>>,
+    mut q_hearts: Query<(Entity, &mut ImageNode, &Heart)>,
+) {
+    let health = match q_player.single() {
+        Ok(p) => p,
+        Err(_) => return,
+    };
+    let root = match q_hearts_containers.single() {
+        Ok(r) => r,
+        Err(_) => return,
+    };
+
+    let count = heart_count(health);
+    let mut spawned_hearts = 0;
+    for (entity, mut ui_image, heart) in &mut q_hearts {
+        if heart.index >= count {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        spawned_hearts += 1;
+        let image = heart_image(&assets, health, heart.index);
+        if ui_image.image != image.image || ui_image.color != image.color {
+            *ui_image = image;
+        }
+    }
+
+    for i in spawned_hearts..count {
+        let heart_entity = spawn_heart(&mut commands, heart_image(&assets, health, i), i);
+        commands.entity(root).add_children(&[heart_entity]);
+    }
+}
+
+fn shake_camera(
     mut shake: ResMut<CameraShake>,
-    q_player: Query<&Health, With<Player>>,
-    mut q_hearts: Query<(&mut ImageNode, &Heart)>,
+    q_player: Query<&Player>,
     mut ev_health_changed: EventReader<HealthChanged>,
 ) {
     for ev in ev_health_changed.read() {
-        let player_health = match q_player.get(ev.entity) {
-            Ok(p) => p,
-            Err(_) => continue,
-        };
-
-        shake.add_trauma(0.5);
-
-        let threshold_index = player_health.health as usize;
-        for (mut ui_image, heart) in &mut q_hearts {
-            if heart.index >= threshold_index {
-                ui_image.image = assets.heart_empty.clone();
-            } else {
-                ui_image.image = assets.heart_full.clone();
-            }
+        if ev.kind != HealthChangeKind::Heal && q_player.contains(ev.entity) {
+            shake.add_trauma(0.5);
         }
     }
 }
@@ -168,7 +313,8 @@ fn full_heal_player(
         Err(_) => return,
     };
 
-    health.health = PLAYER_HEALTH;
+    let max_health = health.max_health;
+    health.heal(max_health);
 }
 
 pub struct HealthPlugin;
@@ -180,8 +326,10 @@ impl Plugin for HealthPlugin {
             (
                 spawn_health_damage_text,
                 check_health_changed,
+                expire_shields,
                 spawn_hearts,
                 update_player_hearts,
+                shake_camera,
                 full_heal_player,
             )
                 .run_if(in_state(GameState::Gaming)),
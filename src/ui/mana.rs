@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use crate::{
+    player::Player,
+    spell::{
+        mana::{Mana, SpellRejectReason, SpellRejected},
+        registry::SpellRegistry,
+    },
+    GameState,
+};
+
+use super::{
+    health::SpawnPlayerHearts,
+    world_text::{SpawnWorldText, WorldText},
+};
+
+const BAR_COLOR: Color = Color::srgb(0.25, 0.45, 0.95);
+const BAR_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+#[derive(Component)]
+struct ManaBarContainer;
+
+#[derive(Component)]
+struct ManaBar;
+
+fn spawn_mana_bar(
+    mut commands: Commands,
+    mut ev_spawn_player_hearts: EventReader<SpawnPlayerHearts>,
+) {
+    for ev in ev_spawn_player_hearts.read() {
+        let bar = commands
+            .spawn((
+                ManaBar,
+                BackgroundColor(BAR_COLOR),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+            ))
+            .id();
+
+        // Hearts are 4% of the 90% wide hearts container each.
+        let hearts_width = 0.9 * 4.0 * ev.count as f32;
+        commands
+            .spawn((
+                ManaBarContainer,
+                BackgroundColor(BAR_BACKGROUND_COLOR),
+                Node {
+                    width: Val::Percent(20.0),
+                    height: Val::Vw(1.2),
+                    top: Val::Percent(5.0),
+                    left: Val::Percent(5.0 + hearts_width + 2.0),
+                    margin: UiRect::top(Val::Vw(1.2)),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+            ))
+            .add_children(&[bar]);
+    }
+}
+
+fn despawn_mana_bar(
+    mut commands: Commands,
+    q_mana_bar_containers: Query<Entity, With<ManaBarContainer>>,
+) {
+    for entity in &q_mana_bar_containers {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn update_mana_bar(
+    q_player: Query<&Mana, With<Player>>,
+    mut q_mana_bars: Query<&mut Node, With<ManaBar>>,
+) {
+    let mana = match q_player.single() {
+        Ok(p) => p,
+        Err(_) => return,
+    };
+
+    for mut node in &mut q_mana_bars {
+        node.width = Val::Percent(100.0 * mana.mana / mana.max_mana);
+    }
+}
+
+fn spawn_spell_rejected_texts(
+    registry: Res<SpellRegistry>,
+    q_player: Query<&Transform, With<Player>>,
+    mut ev_spell_rejected: EventReader<SpellRejected>,
+    mut ev_spawn_world_text: EventWriter<SpawnWorldText>,
+) {
+    let pos = match q_player.single() {
+        Ok(p) => p.translation,
+        Err(_) => return,
+    };
+
+    for ev in ev_spell_rejected.read() {
+        let content = match ev.reason {
+            SpellRejectReason::NoMana => "NO MANA".to_string(),
+            SpellRejectReason::OnCooldown => match registry.entry(&ev.spell) {
+                Some(entry) => format!("{} ON COOLDOWN", entry.title.to_uppercase()),
+                None => "ON COOLDOWN".to_string(),
+            },
+            SpellRejectReason::Locked => "LOCKED".to_string(),
+        };
+
+        ev_spawn_world_text.write(SpawnWorldText {
+            world_text: WorldText {
+                offset: Vec3::new(0.0, 20.0, 10.0),
+                font_scale: 8.0,
+                ..default()
+            },
+            pos,
+            content,
+        });
+    }
+}
+
+pub struct ManaUiPlugin;
+
+impl Plugin for ManaUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_mana_bar, update_mana_bar, spawn_spell_rejected_texts)
+                .run_if(in_state(GameState::Gaming)),
+        )
+        .add_systems(OnEnter(GameState::GameOver), despawn_mana_bar);
+    }
+}
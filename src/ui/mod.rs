@@ -4,10 +4,12 @@ pub mod world_text;
 
 mod game_over_ui;
 mod keyboard_ui;
+mod mana;
 mod platform_arrow;
 mod pop_up;
 mod spell_book;
 mod statue_counter;
+mod target_mode;
 mod vignette;
 mod win_ui;
 
@@ -19,6 +21,7 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             health::HealthPlugin,
+            mana::ManaUiPlugin,
             world_text::WorldTextPlugin,
             text_field::TextFieldPlugin,
             pop_up::PopUpPlugin,
@@ -29,6 +32,7 @@ impl Plugin for UiPlugin {
             vignette::VignettePlugin,
             platform_arrow::PlatformArrowPlugin,
             win_ui::WinUiPlugin,
+            target_mode::TargetModeUiPlugin,
         ));
     }
 }
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use crate::item::item_value::{item_description, item_title};
 use crate::item::statue::StatueUnlockedDelayed;
+use crate::spell::registry::SpellRegistry;
 use crate::{GameAssets, GameState};
 
 const TIME: f32 = 5.0;
@@ -45,9 +46,19 @@ fn spawn_item_description(commands: &mut Commands, font: Handle<Font>, text: Str
     commands.spawn((text, text_font, text_color, layout)).id()
 }
 
-fn spawn_pop_up(commands: &mut Commands, font: Handle<Font>, ev: &StatueUnlockedDelayed) {
-    let title = spawn_item_title(commands, font.clone(), item_title(&ev.statue.item));
-    let description = spawn_item_description(commands, font, item_description(&ev.statue.item));
+fn spawn_pop_up(
+    commands: &mut Commands,
+    font: Handle<Font>,
+    registry: &SpellRegistry,
+    ev: &StatueUnlockedDelayed,
+) {
+    let title = spawn_item_title(
+        commands,
+        font.clone(),
+        item_title(registry, &ev.statue.item),
+    );
+    let description =
+        spawn_item_description(commands, font, item_description(registry, &ev.statue.item));
 
     commands
         .spawn((
@@ -69,10 +80,11 @@ fn spawn_pop_up(commands: &mut Commands, font: Handle<Font>, ev: &StatueUnlocked
 fn spawn_pop_ups(
     mut commands: Commands,
     assets: Res<GameAssets>,
+    registry: Res<SpellRegistry>,
     mut ev_statue_unlocked: EventReader<StatueUnlockedDelayed>,
 ) {
     for ev in ev_statue_unlocked.read() {
-        spawn_pop_up(&mut commands, assets.font.clone(), ev);
+        spawn_pop_up(&mut commands, assets.font.clone(), &registry, ev);
     }
 }
 
@@ -7,6 +7,7 @@ use crate::{
         ActiveItems,
     },
     player::{PlayerChangedState, PlayerState},
+    spell::registry::SpellRegistry,
     GameAssets, GameState,
 };
 use bevy::prelude::*;
@@ -55,6 +56,7 @@ fn spawn_spell_book_view(commands: &mut Commands, assets: &Res<GameAssets>) -> E
 fn spawn_spell_book(
     mut commands: Commands,
     assets: Res<GameAssets>,
+    registry: Res<SpellRegistry>,
     active_items: Res<ActiveItems>,
     mut ev_player_changed_state: EventReader<PlayerChangedState>,
 ) {
@@ -66,7 +68,8 @@ fn spawn_spell_book(
         let background = commands.spawn(BackgroundBundle::new(&assets)).id();
         let hint_up = commands.spawn(MovementHintUpBundle::new(&assets)).id();
         let hint_down = commands.spawn(MovementHintDownBundle::new(&assets)).id();
-        let scrollable_list = spawn_scrollable_list(&mut commands, &assets, &active_items);
+        let scrollable_list =
+            spawn_scrollable_list(&mut commands, &assets, &registry, &active_items);
         let view = spawn_spell_book_view(&mut commands, &assets);
 
         commands.spawn(SpellbookBundle::default()).add_children(&[
@@ -98,6 +101,7 @@ fn despawn_spell_bock(
 
 fn update_view(
     assets: Res<GameAssets>,
+    registry: Res<SpellRegistry>,
     active_items: Res<ActiveItems>,
     q_scrolling_list: Query<&ScrollingList>,
     mut q_view_icon: Query<&mut ImageNode, With<SpellbookViewIcon>>,
@@ -134,9 +138,9 @@ fn update_view(
     }
     let item = &active_items[scrolling_list.index];
 
-    let target_texture = item_icon(&assets, item);
-    let target_title = item_title(item);
-    let target_description = item_description(item);
+    let target_texture = item_icon(&assets, &registry, item);
+    let target_title = item_title(&registry, item);
+    let target_description = item_description(&registry, item);
 
     if icon.image != target_texture {
         icon.image = target_texture;
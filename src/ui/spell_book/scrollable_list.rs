@@ -1,5 +1,6 @@
 use crate::{
     item::{item_value::item_icon, ActiveItems},
+    spell::registry::SpellRegistry,
     GameAssets,
 };
 use bevy::prelude::*;
@@ -28,6 +29,7 @@ const INDEX_THRESHOLD: usize = 1;
 pub fn spawn_scrollable_list(
     commands: &mut Commands,
     assets: &Res<GameAssets>,
+    registry: &SpellRegistry,
     active_items: &Res<ActiveItems>,
 ) -> Entity {
     let moving_panel = commands
@@ -37,7 +39,10 @@ pub fn spawn_scrollable_list(
                 parent
                     .spawn(MovingPanelLabelBundle::new(assets))
                     .with_children(|parent| {
-                        parent.spawn(ScrollingIconBundle::new(item_icon(assets, spell), i));
+                        parent.spawn(ScrollingIconBundle::new(
+                            item_icon(assets, registry, spell),
+                            i,
+                        ));
                     })
                     .with_children(|parent| {
                         if i == 0 {
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::{spell::targeting::TargetingSettings, GameAssets, GameState};
+
+#[derive(Component)]
+struct TargetModeText;
+
+fn spawn_target_mode_text(mut commands: Commands, assets: Res<GameAssets>) {
+    commands.spawn((
+        TargetModeText,
+        Node {
+            top: Val::Px(100.0),
+            right: Val::Px(25.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        Text::from(String::new()),
+        TextFont {
+            font: assets.font.clone(),
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    ));
+}
+
+fn despawn_target_mode_text(mut commands: Commands, q_texts: Query<Entity, With<TargetModeText>>) {
+    for entity in &q_texts {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn update_target_mode_text(
+    settings: Res<TargetingSettings>,
+    q_text: Query<Entity, Added<TargetModeText>>,
+    q_texts: Query<Entity, With<TargetModeText>>,
+    mut writer: TextUiWriter,
+) {
+    // Also fill in freshly spawned texts, the settings might not have changed since.
+    if !settings.is_changed() && q_text.is_empty() {
+        return;
+    }
+
+    for text in &q_texts {
+        *writer.text(text, 0) = format!("TARGET: {}", settings.mode.word().to_uppercase());
+    }
+}
+
+pub struct TargetModeUiPlugin;
+
+impl Plugin for TargetModeUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Gaming), spawn_target_mode_text)
+            .add_systems(
+                Update,
+                update_target_mode_text.run_if(in_state(GameState::Gaming)),
+            )
+            .add_systems(OnEnter(GameState::GameOver), despawn_target_mode_text)
+            .add_systems(OnEnter(GameState::Win), despawn_target_mode_text);
+    }
+}
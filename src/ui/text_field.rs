@@ -1,11 +1,19 @@
+use std::collections::VecDeque;
+
 use bevy::{
     color::palettes::css::RED,
-    input::keyboard::{KeyCode, KeyboardInput},
+    input::keyboard::{Key, KeyCode, KeyboardInput},
     prelude::*,
     window::PrimaryWindow,
 };
 
+use crate::item::ActiveItems;
 use crate::player::{Player, PlayerChangedState, PlayerState};
+use crate::spell::debug_spell::DebugSpell;
+use crate::spell::grammar::{normalize, Modifier, CONJUNCTION};
+use crate::spell::registry::{SpellRegistry, SpellUnlock};
+use crate::spell::targeting::TargetMode;
+use crate::spell::Spell;
 use crate::{GameAssets, GameState};
 
 const TRANSPARENT_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
@@ -13,6 +21,8 @@ const FONT_SIZE_INPUT: f32 = 32.0;
 const CHAR_SIZE: f32 = 2.5;
 const CHAR_OFFSET: f32 = 1.5;
 const CHAR_PIXEL_FACTOR: f32 = 12.8;
+const SUGGESTION_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.35);
+const CAST_HISTORY_SIZE: usize = 20;
 
 #[derive(Component)]
 pub struct CastingText;
@@ -20,18 +30,238 @@ pub struct CastingText;
 struct TypingBuffer;
 #[derive(Component)]
 struct TypingCursor;
+#[derive(Component)]
+struct TypingSuggestion;
 #[derive(Resource)]
 struct TypingCursorTimer(Timer);
 
 #[derive(Event)]
 pub struct TypingSubmitEvent {
     pub value: String,
+    pub metrics: TypingMetrics,
+}
+
+/// How fast and clean a single incantation was typed.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct TypingMetrics {
+    pub keystrokes: usize,
+    pub backspaces: usize,
+    /// Seconds from opening the text field until the first keystroke.
+    pub reaction_time: f32,
+    pub wpm: f32,
+    /// Share of typed characters that made it into the submitted text.
+    pub accuracy: f32,
+    /// Parts of the text were filled in by Tab completion or the cast history,
+    /// so the metrics don't reflect the actual typing.
+    pub assisted: bool,
 }
 
 #[derive(Resource, Default, Debug)]
 pub struct TypingState {
     buf: String,
     just_typed_char: bool,
+    opened_at: f32,
+    keystroke_times: Vec<f32>,
+    backspaces: usize,
+    assisted: bool,
+}
+
+/// The last submitted incantations, most recent first.
+/// Can be cycled through with the arrow keys while casting.
+#[derive(Resource, Default)]
+pub struct CastHistory {
+    entries: VecDeque<String>,
+    /// Index of the entry that is currently recalled into the text field.
+    cursor: Option<usize>,
+    /// What was typed before recalling an entry.
+    draft: String,
+}
+
+/// Incantations that may be suggested while typing, only contains castable spells.
+#[derive(Resource, Default)]
+struct SpellSuggestions {
+    incantations: Vec<String>,
+    /// The remaining part of the current suggestion, shown as ghost text.
+    ghost: String,
+}
+
+/// Typing metrics of all casts in this run, shown on the end screens.
+#[derive(Resource, Default)]
+pub struct TypingSession {
+    pub casts: usize,
+    pub keystrokes: usize,
+    pub backspaces: usize,
+    total_wpm: f32,
+    total_accuracy: f32,
+    total_reaction_time: f32,
+}
+
+impl TypingState {
+    fn reset(&mut self, now: f32) {
+        self.buf.clear();
+        self.opened_at = now;
+        self.keystroke_times.clear();
+        self.backspaces = 0;
+        self.assisted = false;
+    }
+
+    fn metrics(&self, now: f32) -> TypingMetrics {
+        let keystrokes = self.keystroke_times.len();
+        let duration = now - self.opened_at;
+        let chars = self.buf.trim().chars().count();
+
+        // A word is standardized to 5 characters.
+        let wpm = if duration > 0.0 {
+            chars as f32 / 5.0 / (duration / 60.0)
+        } else {
+            0.0
+        };
+        let accuracy = if keystrokes == 0 {
+            1.0
+        } else {
+            (chars as f32 / keystrokes as f32).min(1.0)
+        };
+
+        TypingMetrics {
+            keystrokes,
+            backspaces: self.backspaces,
+            reaction_time: self
+                .keystroke_times
+                .first()
+                .map_or(duration, |t| t - self.opened_at),
+            wpm,
+            accuracy,
+            assisted: self.assisted,
+        }
+    }
+}
+
+impl CastHistory {
+    /// `0` is the most recent incantation.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(|s| s.as_str())
+    }
+
+    fn push(&mut self, incantation: &str) {
+        self.cursor = None;
+        if incantation.is_empty() || self.get(0) == Some(incantation) {
+            return;
+        }
+        if self.entries.len() == CAST_HISTORY_SIZE {
+            self.entries.pop_back();
+        }
+        self.entries.push_front(incantation.to_string());
+    }
+
+    fn older(&mut self, buf: &str) -> Option<String> {
+        let index = match self.cursor {
+            Some(i) => i + 1,
+            None => {
+                self.draft = buf.to_string();
+                0
+            }
+        };
+        let entry = self.get(index)?.to_string();
+        self.cursor = Some(index);
+        Some(entry)
+    }
+
+    fn newer(&mut self) -> Option<String> {
+        match self.cursor? {
+            0 => {
+                self.cursor = None;
+                Some(self.draft.clone())
+            }
+            i => {
+                self.cursor = Some(i - 1);
+                self.get(i - 1).map(|s| s.to_string())
+            }
+        }
+    }
+}
+
+impl SpellSuggestions {
+    /// Complete the spell of the last clause, ignoring conjunctions and modifiers in front of it.
+    /// Returns only the missing rest of the incantation.
+    fn suggestion(&self, buf: &str) -> Option<String> {
+        let buf = normalize(buf);
+        let mut start = 0;
+        let mut offset = 0;
+        for part in buf.split_inclusive(' ') {
+            if !part.ends_with(' ') {
+                break;
+            }
+            let word = part.trim_end();
+            let clause_empty = buf[start..offset].trim().is_empty();
+            offset += part.len();
+            if word == CONJUNCTION || (clause_empty && Modifier::from_word(word).is_some()) {
+                start = offset;
+            }
+        }
+
+        let prefix = buf[start..].trim_start();
+        if prefix.is_empty() {
+            return None;
+        }
+        self.incantations
+            .iter()
+            .find(|s| s.len() > prefix.len() && s.starts_with(prefix))
+            .map(|s| s[prefix.len()..].to_string())
+    }
+}
+
+impl TypingSession {
+    fn record(&mut self, metrics: &TypingMetrics) {
+        self.casts += 1;
+        self.keystrokes += metrics.keystrokes;
+        self.backspaces += metrics.backspaces;
+        self.total_wpm += metrics.wpm;
+        self.total_accuracy += metrics.accuracy;
+        self.total_reaction_time += metrics.reaction_time;
+    }
+
+    pub fn average_wpm(&self) -> f32 {
+        if self.casts == 0 {
+            return 0.0;
+        }
+        self.total_wpm / self.casts as f32
+    }
+
+    pub fn average_accuracy(&self) -> f32 {
+        if self.casts == 0 {
+            return 0.0;
+        }
+        self.total_accuracy / self.casts as f32
+    }
+
+    pub fn average_reaction_time(&self) -> f32 {
+        if self.casts == 0 {
+            return 0.0;
+        }
+        self.total_reaction_time / self.casts as f32
+    }
+
+    /// The summary that is shown on the end screens.
+    pub fn summary_text(&self, font: Handle<Font>) -> impl Bundle {
+        let text = Text::from(format!(
+            "TYPING: {:.0} WPM, {:.0}% ACCURACY\n{} KEYSTROKES, {} BACKSPACES, {:.2}s REACTION",
+            self.average_wpm(),
+            self.average_accuracy() * 100.0,
+            self.keystrokes,
+            self.backspaces,
+            self.average_reaction_time(),
+        ));
+        (
+            text,
+            TextFont {
+                font,
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            TextLayout::new_with_justify(JustifyText::Center),
+        )
+    }
 }
 
 fn trim_last_word(s: &str) -> String {
@@ -118,17 +348,30 @@ fn spawn_text_field(
             TextColor(Color::WHITE),
         ))
         .id();
+    let suggestion = commands
+        .spawn((
+            TypingSuggestion,
+            Text::from(""),
+            TextFont {
+                font: assets.font.clone(),
+                font_size: FONT_SIZE_INPUT,
+                ..default()
+            },
+            TextColor(SUGGESTION_COLOR),
+        ))
+        .id();
 
     commands
         .entity(root)
-        .add_children(&[input_pointer, text, cursor]);
+        .add_children(&[input_pointer, text, cursor, suggestion]);
 }
 
 fn update_buffer_container(
     typing_state: Res<TypingState>,
+    suggestions: Res<SpellSuggestions>,
     mut q_buffer_container: Query<&mut Node, With<CastingText>>,
 ) {
-    if !typing_state.is_changed() {
+    if !typing_state.is_changed() && !suggestions.is_changed() {
         return;
     }
 
@@ -137,7 +380,7 @@ fn update_buffer_container(
         Err(_) => return,
     };
 
-    let k = 2.0 + typing_state.buf.len() as f32;
+    let k = 2.0 + (typing_state.buf.chars().count() + suggestions.ghost.chars().count()) as f32;
     style.width = Val::Px((k * CHAR_SIZE + CHAR_OFFSET) * CHAR_PIXEL_FACTOR);
 }
 
@@ -157,6 +400,69 @@ fn update_buffer_text(
     *writer.text(text, 0) = typing_state.buf.clone();
 }
 
+fn update_spell_suggestions(
+    registry: Res<SpellRegistry>,
+    active_items: Res<ActiveItems>,
+    debug_spell: Res<DebugSpell>,
+    mut suggestions: ResMut<SpellSuggestions>,
+) {
+    if !registry.is_changed() && !active_items.is_changed() && !debug_spell.is_changed() {
+        return;
+    }
+
+    suggestions.incantations = registry
+        .entries()
+        .iter()
+        .filter(|entry| match &entry.unlock {
+            SpellUnlock::Item(item) => debug_spell.active || active_items.contains(item),
+            // Don't give away the debug spell to players.
+            SpellUnlock::Always => entry.spell != Spell::Debug || debug_spell.active,
+            SpellUnlock::DebugOnly => debug_spell.active,
+        })
+        .flat_map(|entry| {
+            let mut incantations = entry.incantations.clone();
+            // Also suggest the target modes that scopus can switch to directly.
+            if entry.spell == Spell::Scopus {
+                for incantation in &entry.incantations {
+                    incantations.extend(
+                        TargetMode::ALL
+                            .iter()
+                            .map(|mode| format!("{} {}", incantation, mode.word())),
+                    );
+                }
+            }
+            incantations
+        })
+        .collect();
+}
+
+fn update_suggestion(typing_state: Res<TypingState>, mut suggestions: ResMut<SpellSuggestions>) {
+    if !typing_state.is_changed() && !suggestions.is_changed() {
+        return;
+    }
+
+    let ghost = suggestions
+        .suggestion(&typing_state.buf)
+        .unwrap_or_default();
+    if suggestions.ghost != ghost {
+        suggestions.ghost = ghost;
+    }
+}
+
+fn update_suggestion_text(
+    suggestions: Res<SpellSuggestions>,
+    q_typing_suggestion_text: Query<Entity, With<TypingSuggestion>>,
+    mut writer: TextUiWriter,
+) {
+    let text = match q_typing_suggestion_text.single() {
+        Ok(t) => t,
+        Err(_) => return,
+    };
+    if writer.text(text, 0).as_str() != suggestions.ghost {
+        *writer.text(text, 0) = suggestions.ghost.clone();
+    }
+}
+
 fn update_cursor_text(
     mut timer: ResMut<TypingCursorTimer>,
     mut query: Query<&mut TextColor, With<TypingCursor>>,
@@ -176,7 +482,11 @@ fn update_cursor_text(
 }
 
 fn push_chars(
+    time: Res<Time>,
     mut typing_state: ResMut<TypingState>,
+    mut typing_session: ResMut<TypingSession>,
+    suggestions: Res<SpellSuggestions>,
+    mut cast_history: ResMut<CastHistory>,
     mut typing_submit_events: EventWriter<TypingSubmitEvent>,
     mut keyboard_input_events: EventReader<KeyboardInput>,
     keys: Res<ButtonInput<KeyCode>>,
@@ -187,7 +497,9 @@ fn push_chars(
         Err(_) => return,
     };
 
-    let control_active = keys.pressed(KeyCode::ControlLeft);
+    // Windows reports AltGr as Ctrl + Alt, which is needed for characters on many layouts.
+    let control_active = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        && !keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
 
     for ev in keyboard_input_events.read() {
         // We run this in the loop so that the events get consumed.
@@ -198,58 +510,68 @@ fn push_chars(
         }
 
         if ev.state.is_pressed() {
-            let maybe_char = match ev.key_code {
-                KeyCode::KeyA => Some('a'),
-                KeyCode::KeyB => Some('b'),
-                KeyCode::KeyC => Some('c'),
-                KeyCode::KeyD => Some('d'),
-                KeyCode::KeyE => Some('e'),
-                KeyCode::KeyF => Some('f'),
-                KeyCode::KeyG => Some('g'),
-                KeyCode::KeyH => Some('h'),
-                KeyCode::KeyI => Some('i'),
-                KeyCode::KeyJ => Some('j'),
-                KeyCode::KeyK => Some('k'),
-                KeyCode::KeyL => Some('l'),
-                KeyCode::KeyM => Some('m'),
-                KeyCode::KeyN => Some('n'),
-                KeyCode::KeyO => Some('o'),
-                KeyCode::KeyP => Some('p'),
-                KeyCode::KeyQ => Some('q'),
-                KeyCode::KeyR => Some('r'),
-                KeyCode::KeyS => Some('s'),
-                KeyCode::KeyT => Some('t'),
-                KeyCode::KeyU => Some('u'),
-                KeyCode::KeyV => Some('v'),
-                KeyCode::KeyW => {
-                    if !control_active {
-                        Some('w')
-                    } else {
-                        typing_state.buf = trim_last_word(&typing_state.buf);
-                        None
-                    }
+            // Use the text the OS produced instead of the physical key,
+            // so that the keyboard layout and characters like `ü` work.
+            let typed: String = if control_active {
+                let is_w =
+                    matches!(&ev.logical_key, Key::Character(c) if c.eq_ignore_ascii_case("w"));
+                if is_w {
+                    typing_state.buf = trim_last_word(&typing_state.buf);
+                    typing_state.backspaces += 1;
                 }
-                KeyCode::KeyX => Some('x'),
-                KeyCode::KeyY => Some('y'),
-                KeyCode::KeyZ => Some('z'),
-                KeyCode::Space => Some(' '),
-                KeyCode::Minus => Some('-'),
-                _ => None,
+                String::new()
+            } else {
+                ev.text
+                    .iter()
+                    .flat_map(|text| text.chars())
+                    .filter(|c| c.is_alphabetic() || *c == ' ' || *c == '-')
+                    .collect()
             };
 
-            if let Some(char) = maybe_char {
-                typing_state.buf.push(char);
+            if !typed.is_empty() {
+                typing_state.buf.push_str(&typed);
+                typing_state.keystroke_times.push(time.elapsed_secs());
                 typing_state.just_typed_char = true;
             } else {
                 typing_state.just_typed_char = false;
             }
 
-            if ev.key_code == KeyCode::Enter {
+            if ev.logical_key == Key::Tab {
+                if let Some(rest) = suggestions.suggestion(&typing_state.buf) {
+                    typing_state.buf.push_str(&rest);
+                    typing_state.assisted = true;
+                }
+            }
+
+            if ev.logical_key == Key::ArrowUp {
+                if let Some(entry) = cast_history.older(&typing_state.buf) {
+                    typing_state.buf = entry;
+                    typing_state.assisted = true;
+                }
+            }
+
+            if ev.logical_key == Key::ArrowDown {
+                if let Some(entry) = cast_history.newer() {
+                    typing_state.buf = entry;
+                    typing_state.assisted = true;
+                }
+            }
+
+            if ev.logical_key == Key::Enter {
                 let text = typing_state.buf.clone();
-                typing_submit_events.write(TypingSubmitEvent { value: text });
+                cast_history.push(text.trim());
+                let metrics = typing_state.metrics(time.elapsed_secs());
+                if !text.trim().is_empty() && !metrics.assisted {
+                    typing_session.record(&metrics);
+                }
+                typing_submit_events.write(TypingSubmitEvent {
+                    value: text,
+                    metrics,
+                });
             }
 
-            if ev.key_code == KeyCode::Backspace {
+            if ev.logical_key == Key::Backspace {
+                typing_state.backspaces += 1;
                 if !control_active {
                     typing_state.buf.pop();
                 } else {
@@ -263,13 +585,16 @@ fn push_chars(
 fn spawn_casting_text(
     mut commands: Commands,
     assets: Res<GameAssets>,
+    time: Res<Time>,
     mut typing_state: ResMut<TypingState>,
+    mut cast_history: ResMut<CastHistory>,
     q_window: Query<&Window, With<PrimaryWindow>>,
     mut ev_player_changed_state: EventReader<PlayerChangedState>,
 ) {
     for ev in ev_player_changed_state.read() {
         if ev.new_state == PlayerState::Casting {
-            typing_state.buf.clear();
+            typing_state.reset(time.elapsed_secs());
+            cast_history.cursor = None;
             spawn_text_field(&mut commands, &assets, &q_window);
         }
     }
@@ -301,14 +626,22 @@ impl Plugin for TextFieldPlugin {
             TimerMode::Repeating,
         )))
         .init_resource::<TypingState>()
+        .init_resource::<TypingSession>()
+        .init_resource::<SpellSuggestions>()
+        .init_resource::<CastHistory>()
         .add_event::<TypingSubmitEvent>()
         .add_systems(
             Update,
             (
                 push_chars,
                 update_cursor_text,
-                update_buffer_container.after(push_chars),
+                update_spell_suggestions
+                    .before(push_chars)
+                    .run_if(in_state(GameState::Gaming)),
+                update_suggestion.after(push_chars),
+                update_buffer_container.after(update_suggestion),
                 update_buffer_text.after(push_chars),
+                update_suggestion_text.after(update_suggestion),
                 despawn_casting_text,
                 spawn_casting_text.run_if(in_state(GameState::Gaming)),
             ),
@@ -1,4 +1,5 @@
 pub mod anim_sprite;
+pub mod ron_asset;
 
 mod diagnostics;
 
@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+
+/// An asset that is read from a RON file through `RonAssetLoader`.
+pub trait RonAsset: Asset + Sized {
+    /// What the RON file deserializes into.
+    type Def: DeserializeOwned;
+
+    fn extensions() -> &'static [&'static str];
+
+    fn from_def(def: Self::Def, load_context: &mut LoadContext<'_>) -> Self;
+}
+
+#[derive(Debug)]
+pub enum RonAssetLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl Display for RonAssetLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RonAssetLoaderError::Io(err) => {
+                write!(f, "could not read ron asset, {}", err)
+            }
+            RonAssetLoaderError::Ron(err) => {
+                write!(f, "could not parse ron asset, {}", err)
+            }
+        }
+    }
+}
+
+impl Error for RonAssetLoaderError {}
+
+impl From<std::io::Error> for RonAssetLoaderError {
+    fn from(err: std::io::Error) -> Self {
+        RonAssetLoaderError::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for RonAssetLoaderError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        RonAssetLoaderError::Ron(err)
+    }
+}
+
+pub struct RonAssetLoader<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for RonAssetLoader<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: RonAsset> AssetLoader for RonAssetLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = RonAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let def: A::Def = ron::de::from_bytes(&bytes)?;
+        Ok(A::from_def(def, load_context))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        A::extensions()
+    }
+}
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 use bevy_rapier2d::geometry::Collider;
 
+use super::navigation::NavObstacle;
 use super::CHUNK_SIZE;
 use crate::player::Player;
 use crate::{GameAssets, GameState};
@@ -62,20 +63,30 @@ fn iid_from_map_indices(x_index: i32, y_index: i32) -> String {
     IIDS[index].to_string()
 }
 
+/// Inverse of `iid_from_map_indices`.
+pub fn map_indices_from_iid(iid: &str) -> Option<(i32, i32)> {
+    let index = IIDS.iter().position(|i| *i == iid)?;
+    Some(((index % CHUNK_ROWS) as i32, (index / CHUNK_ROWS) as i32))
+}
+
 fn spawn_world_borders(mut commands: Commands) {
     commands.spawn((
+        NavObstacle,
         Collider::cuboid(10.0, 2600.0),
         Transform::from_translation(Vec3::new(0.0, 2600.0, 0.0)),
     ));
     commands.spawn((
+        NavObstacle,
         Collider::cuboid(10.0, 2600.0),
         Transform::from_translation(Vec3::new(5120.0, 2600.0, 0.0)),
     ));
     commands.spawn((
+        NavObstacle,
         Collider::cuboid(2600.0, 10.0),
         Transform::from_translation(Vec3::new(2610.0, 55.0, 0.0)),
     ));
     commands.spawn((
+        NavObstacle,
         Collider::cuboid(2600.0, 10.0),
         Transform::from_translation(Vec3::new(2610.0, 5120.0, 0.0)),
     ));
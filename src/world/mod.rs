@@ -1,5 +1,6 @@
 pub mod camera;
 pub mod camera_shake;
+pub mod navigation;
 
 mod map;
 mod rapier_debug;
@@ -21,6 +22,7 @@ impl Plugin for WorldPlugin {
             camera::CameraPlugin,
             camera_shake::CameraShakePlugin,
             map::MapPlugin,
+            navigation::NavigationPlugin,
             rapier_debug::RapierDebugPlugin,
         ))
         .add_systems(Startup, configure_physics);
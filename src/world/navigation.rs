@@ -0,0 +1,419 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_ecs_ldtk::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::map::map_indices_from_iid;
+use super::CHUNK_SIZE;
+use crate::GameState;
+
+/// The size of the LDtk grid.
+const CELL_SIZE: f32 = 32.0;
+const CHUNK_CELLS: i32 = (CHUNK_SIZE / CELL_SIZE) as i32;
+/// Give the physics a moment to pick up the new colliders before rasterizing them.
+const REBUILD_DELAY: f32 = 0.1;
+/// Upper bound of expanded cells per path search, keeps searches to unreachable cells cheap.
+const MAX_SEARCH: usize = 1024;
+/// How many waypoints ahead are checked for a straight line, see `NavGrid::direction`.
+const LOOKAHEAD: usize = 8;
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// Static colliders that should block paths.
+/// LDtk levels are rasterized when they spawn, everything that spawns at runtime
+/// (e.g. statue walls) needs this component to update the grid.
+#[derive(Component)]
+pub struct NavObstacle;
+
+struct NavChunk {
+    blocked: Vec<bool>,
+}
+
+impl NavChunk {
+    fn index(cell: IVec2) -> usize {
+        let local = cell.rem_euclid(IVec2::splat(CHUNK_CELLS));
+        (local.x + local.y * CHUNK_CELLS) as usize
+    }
+}
+
+/// Grid of blocked cells, built per map chunk from the static colliders.
+/// Cells in chunks that aren't loaded are never blocked.
+#[derive(Resource, Default)]
+pub struct NavGrid {
+    chunks: HashMap<IVec2, NavChunk>,
+    dirty_chunks: Vec<(IVec2, Timer)>,
+    /// The chunks each obstacle covers, so they can be rebuilt once it's gone.
+    obstacles: HashMap<Entity, Vec<IVec2>>,
+}
+
+pub fn cell_from_pos(pos: Vec2) -> IVec2 {
+    (pos / CELL_SIZE).floor().as_ivec2()
+}
+
+fn pos_from_cell(cell: IVec2) -> Vec2 {
+    (cell.as_vec2() + 0.5) * CELL_SIZE
+}
+
+fn chunk_from_cell(cell: IVec2) -> IVec2 {
+    cell.div_euclid(IVec2::splat(CHUNK_CELLS))
+}
+
+fn octile_distance(a: IVec2, b: IVec2) -> u32 {
+    let d = (a - b).abs();
+    let (min, max) = (d.x.min(d.y) as u32, d.x.max(d.y) as u32);
+    DIAGONAL_COST * min + STRAIGHT_COST * (max - min)
+}
+
+impl NavGrid {
+    pub fn is_blocked(&self, pos: Vec2) -> bool {
+        self.is_cell_blocked(cell_from_pos(pos))
+    }
+
+    fn is_cell_blocked(&self, cell: IVec2) -> bool {
+        self.chunks
+            .get(&chunk_from_cell(cell))
+            .is_some_and(|chunk| chunk.blocked[NavChunk::index(cell)])
+    }
+
+    fn mark_dirty(&mut self, chunk: IVec2) {
+        if self.dirty_chunks.iter().any(|(c, _)| *c == chunk) {
+            return;
+        }
+        self.dirty_chunks
+            .push((chunk, Timer::from_seconds(REBUILD_DELAY, TimerMode::Once)));
+    }
+
+    /// Whether a straight line between the points doesn't cross any blocked cell.
+    pub fn line_of_sight(&self, from: Vec2, to: Vec2) -> bool {
+        let steps = (from.distance(to) / (CELL_SIZE / 2.0)).ceil() as usize;
+        (0..=steps).all(|i| {
+            let t = if steps == 0 {
+                1.0
+            } else {
+                i as f32 / steps as f32
+            };
+            !self.is_blocked(from.lerp(to, t))
+        })
+    }
+
+    /// A* over the cells, returns the cell centers from `from` to `to`, excluding the start.
+    /// If both are in the same cell, the path only holds its center.
+    pub fn find_path(&self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        let start = cell_from_pos(from);
+        let goal = cell_from_pos(to);
+        if self.is_cell_blocked(goal) {
+            return None;
+        }
+
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<IVec2, IVec2> = HashMap::default();
+        let mut costs: HashMap<IVec2, u32> = HashMap::default();
+        open.push(Reverse((octile_distance(start, goal), 0, start.x, start.y)));
+        costs.insert(start, 0);
+
+        let mut expanded = 0;
+        while let Some(Reverse((_, cost, x, y))) = open.pop() {
+            let cell = IVec2::new(x, y);
+            if cell == goal {
+                let mut path = vec![pos_from_cell(cell)];
+                let mut current = cell;
+                while let Some(previous) = came_from.get(&current) {
+                    if *previous == start {
+                        break;
+                    }
+                    path.push(pos_from_cell(*previous));
+                    current = *previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if costs.get(&cell).is_some_and(|c| *c < cost) {
+                continue;
+            }
+
+            expanded += 1;
+            if expanded > MAX_SEARCH {
+                return None;
+            }
+
+            for (dx, dy) in NEIGHBOURS {
+                let next = cell + IVec2::new(dx, dy);
+                if self.is_cell_blocked(next) {
+                    continue;
+                }
+                // Don't cut corners of blocked cells.
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal
+                    && (self.is_cell_blocked(cell + IVec2::new(dx, 0))
+                        || self.is_cell_blocked(cell + IVec2::new(0, dy)))
+                {
+                    continue;
+                }
+
+                let next_cost = cost
+                    + if diagonal {
+                        DIAGONAL_COST
+                    } else {
+                        STRAIGHT_COST
+                    };
+                if costs.get(&next).is_some_and(|c| *c <= next_cost) {
+                    continue;
+                }
+                costs.insert(next, next_cost);
+                came_from.insert(next, cell);
+                open.push(Reverse((
+                    next_cost + octile_distance(next, goal),
+                    next_cost,
+                    next.x,
+                    next.y,
+                )));
+            }
+        }
+        None
+    }
+
+    /// The direction to walk in to get from `from` to `to` around walls.
+    /// Falls back to the straight direction if there is no path.
+    pub fn direction(&self, from: Vec2, to: Vec2) -> Vec2 {
+        let straight = (to - from).normalize_or_zero();
+        if self.line_of_sight(from, to) {
+            return straight;
+        }
+
+        match self.find_path(from, to) {
+            Some(path) => self.follow_path(from, &path).unwrap_or(straight),
+            None => straight,
+        }
+    }
+
+    /// The direction to walk in to follow a path from `find_path`, `None` if the path is empty.
+    pub fn follow_path(&self, from: Vec2, path: &[Vec2]) -> Option<Vec2> {
+        // Head for the furthest waypoint that can be reached in a straight line.
+        let waypoint = path
+            .iter()
+            .take(LOOKAHEAD)
+            .rev()
+            .find(|waypoint| self.line_of_sight(from, **waypoint))
+            .or(path.first())?;
+        Some((*waypoint - from).normalize_or_zero())
+    }
+}
+
+fn rasterize_chunk(rapier_context: &RapierContext, chunk: IVec2) -> NavChunk {
+    let shape = Collider::cuboid(CELL_SIZE / 2.0, CELL_SIZE / 2.0);
+    let filter = QueryFilter::only_fixed().exclude_sensors();
+
+    let mut blocked = vec![false; (CHUNK_CELLS * CHUNK_CELLS) as usize];
+    for y in 0..CHUNK_CELLS {
+        for x in 0..CHUNK_CELLS {
+            let cell = chunk * CHUNK_CELLS + IVec2::new(x, y);
+            rapier_context.intersections_with_shape(
+                pos_from_cell(cell),
+                0.0,
+                &shape,
+                filter,
+                |_| {
+                    blocked[NavChunk::index(cell)] = true;
+                    false
+                },
+            );
+        }
+    }
+    NavChunk { blocked }
+}
+
+fn mark_level_chunks(mut nav_grid: ResMut<NavGrid>, mut ev_level: EventReader<LevelEvent>) {
+    for ev in ev_level.read() {
+        match ev {
+            LevelEvent::Transformed(iid) => {
+                if let Some((x, y)) = map_indices_from_iid(iid.get()) {
+                    nav_grid.mark_dirty(IVec2::new(x, y));
+                }
+            }
+            LevelEvent::Despawned(iid) => {
+                if let Some((x, y)) = map_indices_from_iid(iid.get()) {
+                    nav_grid.chunks.remove(&IVec2::new(x, y));
+                }
+            }
+            LevelEvent::SpawnTriggered(_) | LevelEvent::Spawned(_) => {}
+        }
+    }
+}
+
+fn mark_obstacle_chunks(
+    mut nav_grid: ResMut<NavGrid>,
+    q_obstacles: Query<(Entity, &Collider, &Transform), Added<NavObstacle>>,
+    mut removed_obstacles: RemovedComponents<NavObstacle>,
+) {
+    for (entity, collider, transform) in &q_obstacles {
+        let aabb = collider.raw.compute_local_aabb();
+        let pos = transform.translation.truncate();
+        let min = chunk_from_cell(cell_from_pos(pos + Vec2::new(aabb.mins.x, aabb.mins.y)));
+        let max = chunk_from_cell(cell_from_pos(pos + Vec2::new(aabb.maxs.x, aabb.maxs.y)));
+
+        let mut chunks = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                chunks.push(IVec2::new(x, y));
+                nav_grid.mark_dirty(IVec2::new(x, y));
+            }
+        }
+        nav_grid.obstacles.insert(entity, chunks);
+    }
+
+    for entity in removed_obstacles.read() {
+        if let Some(chunks) = nav_grid.obstacles.remove(&entity) {
+            for chunk in chunks {
+                nav_grid.mark_dirty(chunk);
+            }
+        }
+    }
+}
+
+fn rebuild_dirty_chunks(
+    time: Res<Time>,
+    rapier_context: ReadRapierContext,
+    mut nav_grid: ResMut<NavGrid>,
+) {
+    let rapier_context = match rapier_context.single() {
+        Ok(r) => r,
+        Err(_) => return,
+    };
+
+    let mut ready = Vec::new();
+    nav_grid.dirty_chunks.retain_mut(|(chunk, timer)| {
+        timer.tick(time.delta());
+        if timer.finished() {
+            ready.push(*chunk);
+        }
+        !timer.finished()
+    });
+
+    for chunk in ready {
+        let nav_chunk = rasterize_chunk(&rapier_context, chunk);
+        nav_grid.chunks.insert(chunk, nav_chunk);
+    }
+}
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavGrid>().add_systems(
+            Update,
+            (
+                mark_level_chunks,
+                mark_obstacle_chunks,
+                rebuild_dirty_chunks
+                    .after(mark_level_chunks)
+                    .after(mark_obstacle_chunks),
+            )
+                .run_if(in_state(GameState::Gaming)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A grid with a single loaded chunk at the origin.
+    fn grid(blocked_cells: &[(i32, i32)]) -> NavGrid {
+        let mut blocked = vec![false; (CHUNK_CELLS * CHUNK_CELLS) as usize];
+        for (x, y) in blocked_cells {
+            blocked[NavChunk::index(IVec2::new(*x, *y))] = true;
+        }
+
+        let mut nav_grid = NavGrid::default();
+        nav_grid.chunks.insert(IVec2::ZERO, NavChunk { blocked });
+        nav_grid
+    }
+
+    fn pos(x: i32, y: i32) -> Vec2 {
+        pos_from_cell(IVec2::new(x, y))
+    }
+
+    #[test]
+    fn path_excludes_start() {
+        let nav_grid = grid(&[]);
+        assert_eq!(
+            nav_grid.find_path(pos(2, 2), pos(5, 2)),
+            Some(vec![pos(3, 2), pos(4, 2), pos(5, 2)])
+        );
+    }
+
+    #[test]
+    fn path_to_own_cell_is_the_cell_center() {
+        let nav_grid = grid(&[]);
+        assert_eq!(
+            nav_grid.find_path(pos(2, 2) + Vec2::splat(5.0), pos(2, 2)),
+            Some(vec![pos(2, 2)])
+        );
+    }
+
+    #[test]
+    fn no_path_to_blocked_goal() {
+        let nav_grid = grid(&[(5, 5)]);
+        assert_eq!(nav_grid.find_path(pos(2, 2), pos(5, 5)), None);
+    }
+
+    #[test]
+    fn path_goes_around_walls() {
+        let nav_grid = grid(&[(4, 1), (4, 2), (4, 3)]);
+        let path = nav_grid.find_path(pos(2, 2), pos(6, 2)).unwrap();
+
+        assert_eq!(path.last(), Some(&pos(6, 2)));
+        assert!(path.iter().all(|waypoint| !nav_grid.is_blocked(*waypoint)));
+    }
+
+    #[test]
+    fn path_does_not_cut_corners() {
+        let nav_grid = grid(&[(3, 2)]);
+        assert_eq!(
+            nav_grid.find_path(pos(2, 2), pos(3, 3)),
+            Some(vec![pos(2, 3), pos(3, 3)])
+        );
+    }
+
+    #[test]
+    fn search_gives_up_on_enclosed_goal() {
+        // Cells outside of loaded chunks are free, so without the cutoff this would never end.
+        let nav_grid = grid(&[
+            (9, 9),
+            (10, 9),
+            (11, 9),
+            (9, 10),
+            (11, 10),
+            (9, 11),
+            (10, 11),
+            (11, 11),
+        ]);
+        assert_eq!(nav_grid.find_path(pos(2, 2), pos(10, 10)), None);
+    }
+
+    #[test]
+    fn direction_is_straight_in_line_of_sight() {
+        let nav_grid = grid(&[]);
+        assert_eq!(nav_grid.direction(pos(2, 2), pos(6, 2)), Vec2::X);
+    }
+
+    #[test]
+    fn direction_leads_around_walls() {
+        let nav_grid = grid(&[(4, 0), (4, 1), (4, 2), (4, 3)]);
+        let direction = nav_grid.direction(pos(2, 2), pos(6, 2));
+        assert!(direction.y > 0.0);
+    }
+}